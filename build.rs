@@ -0,0 +1,54 @@
+//! Generates the solution registry of the `solutions` binary.
+//!
+//! Every scaffolded day in `src/bin` is included as a module, and its `SOLUTION` constant is
+//! collected into `SOLUTIONS`. See `template::registry` for how the registry is used.
+use std::{env, fs, path::Path};
+
+fn main() {
+    // only the `solutions` binary includes the days, other builds don't rerun when a day changes.
+    if env::var_os("CARGO_FEATURE_REGISTRY").is_none() {
+        println!("cargo:rerun-if-changed=build.rs");
+        return;
+    }
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut days: Vec<String> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let name = entry.file_name().into_string().ok()?;
+                    let day = name.strip_suffix(".rs")?;
+                    let is_day = day.len() == 2 && day.chars().all(|c| c.is_ascii_digit());
+                    is_day.then(|| day.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort();
+
+    let mut source = String::new();
+
+    for day in &days {
+        let path = bin_dir.join(format!("{day}.rs"));
+        source.push_str(&format!(
+            "#[path = {:?}]\nmod day{day};\n",
+            path.display().to_string()
+        ));
+    }
+
+    source
+        .push_str("\npub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n");
+    for day in &days {
+        source.push_str(&format!("    day{day}::SOLUTION,\n"));
+    }
+    source.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, source).unwrap();
+}
@@ -0,0 +1,383 @@
+/// Command-line arguments of the `advent_of_code` and `solutions` binaries.
+use advent_of_code::template::{
+    cli::{self, Command, Shell},
+    commands::solve::Watch,
+    compare::DEFAULT_THRESHOLD,
+    leaderboard::Scoring,
+    params,
+    report::Format,
+    runner::{BenchConfig, InputSource, RunOptions},
+    scaffolds::DEFAULT_TEMPLATE,
+    Day,
+};
+use std::{fmt::Display, path::PathBuf, time::Duration};
+
+pub enum AppArguments {
+    Download {
+        day: Day,
+    },
+    Read {
+        day: Day,
+    },
+    Leaderboard {
+        path: PathBuf,
+        day: Option<Day>,
+        scoring: Scoring,
+    },
+    Examples {
+        day: Day,
+        overwrite: bool,
+    },
+    Scaffold {
+        day: Day,
+        download: bool,
+        overwrite: bool,
+        template: String,
+    },
+    Solve {
+        day: Day,
+        release: bool,
+        dhat: bool,
+        options: RunOptions,
+        watch: Option<Watch>,
+    },
+    All {
+        release: bool,
+        isolated: bool,
+        verify: bool,
+        part: Option<u8>,
+        timeout: Option<Duration>,
+        jobs: Option<usize>,
+    },
+    Accept {
+        day: Option<Day>,
+        isolated: bool,
+    },
+    Time {
+        all: bool,
+        day: Option<Day>,
+        store: bool,
+        isolated: bool,
+        bench: BenchConfig,
+        compare: Option<f64>,
+        part: Option<u8>,
+        timeout: Option<Duration>,
+        history: bool,
+        trend: bool,
+    },
+    Report {
+        format: Format,
+        out: Option<PathBuf>,
+    },
+    Status {
+        test: bool,
+    },
+    #[cfg(feature = "today")]
+    Today {
+        wait: bool,
+    },
+    Completions {
+        shell: Shell,
+    },
+    /// Print the help of a command, or the usage summary.
+    Help {
+        command: Option<&'static Command>,
+    },
+}
+
+impl AppArguments {
+    /// Whether the command runs the days in-process, which happens in the `solutions` binary.
+    /// Isolated runs, time limits and parallel jobs start a process per day instead.
+    pub fn runs_in_process(&self) -> bool {
+        match self {
+            AppArguments::All {
+                isolated,
+                timeout,
+                jobs,
+                ..
+            } => !isolated && timeout.is_none() && jobs.is_none(),
+            AppArguments::Accept { isolated, .. } => !isolated,
+            AppArguments::Time {
+                isolated,
+                timeout,
+                history,
+                ..
+            } => !isolated && timeout.is_none() && !history,
+            _ => false,
+        }
+    }
+}
+
+/// An invalid command line, printed together with a hint on how to get help.
+pub struct UsageError {
+    message: String,
+    command: Option<&'static Command>,
+}
+
+impl Display for UsageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Error: {}", self.message)?;
+        match self.command {
+            Some(command) => write!(
+                f,
+                "\nRun `cargo {} --help` for the options of this command.",
+                command.name
+            ),
+            None => write!(f, "\n{}", cli::usage().trim_end()),
+        }
+    }
+}
+
+/// Parse an option whose value is a part, i.e. 1 or 2.
+fn parse_part_option(
+    args: &mut pico_args::Arguments,
+    name: &'static str,
+) -> Result<Option<u8>, Box<dyn std::error::Error>> {
+    match args.opt_value_from_str::<_, String>(name)?.as_deref() {
+        None => Ok(None),
+        Some("1") => Ok(Some(1)),
+        Some("2") => Ok(Some(2)),
+        Some(part) => Err(format!("{name} must be 1 or 2, got `{part}`.").into()),
+    }
+}
+
+/// Parse the `--part` option, which selects a single part to run.
+fn parse_part(args: &mut pico_args::Arguments) -> Result<Option<u8>, Box<dyn std::error::Error>> {
+    parse_part_option(args, "--part")
+}
+
+/// Parse the `--timeout` option, a time limit per day in seconds.
+fn parse_timeout(
+    args: &mut pico_args::Arguments,
+) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+    match args.opt_value_from_str::<_, f64>("--timeout")? {
+        Some(secs) if !secs.is_finite() || secs <= 0.0 => {
+            Err(format!("--timeout must be a positive number of seconds, got {secs}.").into())
+        }
+        secs => Ok(secs.map(Duration::from_secs_f64)),
+    }
+}
+
+/// Parse the `--jobs` option, the number of days to run concurrently.
+fn parse_jobs(
+    args: &mut pico_args::Arguments,
+) -> Result<Option<usize>, Box<dyn std::error::Error>> {
+    match args.opt_value_from_str(["-j", "--jobs"])? {
+        Some(0) => Err("--jobs must be at least 1.".into()),
+        jobs => Ok(jobs),
+    }
+}
+
+pub fn parse() -> Result<AppArguments, UsageError> {
+    let mut args = pico_args::Arguments::from_env();
+    let error = |message: String, command| UsageError { message, command };
+
+    let name = args.subcommand().map_err(|e| error(e.to_string(), None))?;
+    let help = args.contains(["-h", "--help"]);
+
+    let Some(name) = name else {
+        return match help {
+            true => Ok(AppArguments::Help { command: None }),
+            false => Err(error("no command specified.".into(), None)),
+        };
+    };
+
+    let command =
+        cli::find(&name).ok_or_else(|| error(format!("unknown command `{name}`."), None))?;
+
+    if help {
+        return Ok(AppArguments::Help {
+            command: Some(command),
+        });
+    }
+
+    // free-standing arguments are parsed last and would swallow unknown flags, check them first.
+    let rest = args.finish();
+    let strings: Vec<String> = rest
+        .iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    command
+        .check_flags(&strings)
+        .map_err(|message| error(message, Some(command)))?;
+
+    let mut args = pico_args::Arguments::from_vec(rest);
+    let app_args =
+        parse_command(command.name, &mut args).map_err(|e| error(e.to_string(), Some(command)))?;
+
+    let remaining = args.finish();
+    if !remaining.is_empty() {
+        let remaining: Vec<_> = remaining.iter().map(|arg| arg.to_string_lossy()).collect();
+        return Err(error(
+            format!("unexpected argument(s): {}.", remaining.join(" ")),
+            Some(command),
+        ));
+    }
+
+    Ok(app_args)
+}
+
+fn parse_command(
+    name: &str,
+    args: &mut pico_args::Arguments,
+) -> Result<AppArguments, Box<dyn std::error::Error>> {
+    let app_args = match name {
+        "all" => AppArguments::All {
+            release: args.contains("--release"),
+            isolated: args.contains("--isolated"),
+            verify: args.contains("--verify"),
+            part: parse_part(args)?,
+            timeout: parse_timeout(args)?,
+            jobs: parse_jobs(args)?,
+        },
+        "accept" => {
+            let isolated = args.contains("--isolated");
+
+            AppArguments::Accept {
+                day: args.opt_free_from_str()?,
+                isolated,
+            }
+        }
+        "time" => {
+            let all = args.contains("--all");
+            let store = args.contains("--store");
+            let isolated = args.contains("--isolated");
+            let history = args.contains("--history");
+            let trend = args.contains("--trend");
+
+            if trend && !store {
+                return Err("--trend can only be used with --store.".into());
+            }
+
+            let bench = BenchConfig::parse(|name| args.opt_value_from_str(name))?;
+
+            let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+            let compare = (args.contains("--compare") || threshold.is_some())
+                .then(|| threshold.unwrap_or(DEFAULT_THRESHOLD));
+            let part = parse_part(args)?;
+            let timeout = parse_timeout(args)?;
+
+            AppArguments::Time {
+                all,
+                day: args.opt_free_from_str()?,
+                store,
+                isolated,
+                bench,
+                compare,
+                part,
+                timeout,
+                history,
+                trend,
+            }
+        }
+        "report" => AppArguments::Report {
+            format: args
+                .opt_value_from_str("--format")?
+                .unwrap_or(Format::Markdown),
+            out: args.opt_value_from_os_str("--out", |s| {
+                Ok::<_, std::convert::Infallible>(PathBuf::from(s))
+            })?,
+        },
+        "status" => AppArguments::Status {
+            test: args.contains("--test"),
+        },
+        "download" => AppArguments::Download {
+            day: args.free_from_str()?,
+        },
+        "read" => AppArguments::Read {
+            day: args.free_from_str()?,
+        },
+        "leaderboard" => AppArguments::Leaderboard {
+            day: args.opt_value_from_str("--day")?,
+            scoring: args.opt_value_from_str("--scoring")?.unwrap_or_default(),
+            path: args.free_from_os_str(|s| Ok::<_, std::convert::Infallible>(PathBuf::from(s)))?,
+        },
+        "examples" => AppArguments::Examples {
+            overwrite: args.contains("--overwrite"),
+            day: args.free_from_str()?,
+        },
+        "scaffold" => AppArguments::Scaffold {
+            day: args.free_from_str()?,
+            download: args.contains("--download"),
+            overwrite: args.contains("--overwrite"),
+            template: args
+                .opt_value_from_str("--template")?
+                .unwrap_or_else(|| DEFAULT_TEMPLATE.into()),
+        },
+        "solve" => {
+            let input = InputSource::from_options(
+                args.opt_value_from_str("--input")?,
+                args.contains("--example"),
+                args.opt_value_from_str("--example-part")?,
+            )?;
+
+            let submit = parse_part_option(args, "--submit")?;
+            let verify = args.contains("--verify");
+            let part = parse_part(args)?;
+
+            let params = args
+                .values_from_str::<_, String>("--param")?
+                .iter()
+                .map(|pair| params::parse_value(pair))
+                .collect::<Result<_, _>>()?;
+
+            let watch = args.contains("--watch").then(|| Watch {
+                test: args.contains("--test"),
+            });
+
+            if watch.is_some() && submit.is_some() {
+                return Err("--submit cannot be used with --watch.".into());
+            }
+
+            if let (Some(submit), Some(part)) = (submit, part) {
+                if submit != part {
+                    return Err(format!(
+                        "cannot submit part {submit} when only running part {part}."
+                    )
+                    .into());
+                }
+            }
+
+            if input != InputSource::Puzzle && (submit.is_some() || verify) {
+                return Err("--submit and --verify can only be used with the puzzle input.".into());
+            }
+
+            AppArguments::Solve {
+                day: args.free_from_str()?,
+                release: args.contains("--release"),
+                dhat: args.contains("--dhat"),
+                options: RunOptions {
+                    submit,
+                    verify,
+                    input,
+                    part,
+                    params,
+                    ..RunOptions::default()
+                },
+                watch,
+            }
+        }
+        #[cfg(feature = "today")]
+        "today" => AppArguments::Today {
+            wait: args.contains("--wait"),
+        },
+        #[cfg(not(feature = "today"))]
+        "today" => {
+            return Err("`today` requires the `today` feature, run it with `cargo today`.".into())
+        }
+        "completions" => AppArguments::Completions {
+            shell: args.free_from_str()?,
+        },
+        "help" => AppArguments::Help {
+            command: match args.opt_free_from_str::<String>()? {
+                Some(name) => {
+                    Some(cli::find(&name).ok_or_else(|| format!("unknown command `{name}`."))?)
+                }
+                None => None,
+            },
+        },
+        name => return Err(format!("unknown command `{name}`.").into()),
+    };
+
+    Ok(app_args)
+}
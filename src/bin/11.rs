@@ -49,7 +49,7 @@ fn naive(mut start: Vec<usize>, iters: usize) -> Vec<usize> {
 
 fn split_if_even_digits(n: &usize) -> Option<(usize, usize)> {
     let ns = n.to_string();
-    if ns.len() % 2 == 0 {
+    if ns.len().is_multiple_of(2) {
         let (a, b) = ns.split_at(ns.len() / 2);
         return Some((a.parse().unwrap(), b.parse().unwrap()));
     }
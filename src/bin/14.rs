@@ -12,17 +12,19 @@ use nom::{
     IResult,
 };
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(
+    14,
+    params {
+        size: (i32, i32) = (101, 103)
+    }
+);
 
 struct Sentinel {
     pos: IVec2,
     speed: IVec2,
 }
-pub fn part_one(input: &str) -> Option<usize> {
-    part_one_with_params(input, IVec2::new(101, 103))
-}
-
-fn part_one_with_params(input: &str, size: IVec2) -> Option<usize> {
+pub fn part_one(input: &str, params: &Params) -> Option<usize> {
+    let size = IVec2::from(params.size);
     let sentinels = parse_input(input);
 
     let finals: Vec<_> = sentinels
@@ -54,8 +56,8 @@ fn part_one_with_params(input: &str, size: IVec2) -> Option<usize> {
     Some(q1 * q2 * q3 * q4)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let size = IVec2::new(101, 103);
+pub fn part_two(input: &str, params: &Params) -> Option<usize> {
+    let size = IVec2::from(params.size);
     let sentinels = parse_input(input);
 
     let mut step = 1;
@@ -121,7 +123,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::template::read_file("examples", DAY);
-        let result = part_one_with_params(&input, IVec2::new(11, 7));
+        let result = part_one(&input, &Params::read("examples"));
 
         assert_eq!(result, Some(12));
     }
@@ -129,7 +131,7 @@ mod tests {
     #[test]
     fn test_part_two() {
         // We don't have a test case for part 2.
-        // let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        // let result = part_two(&advent_of_code::template::read_file("examples", DAY), &Params::read("examples"));
         // assert_eq!(result, None);
     }
 
@@ -25,7 +25,7 @@ fn next(grid: &HashSet<IVec2>, s: &State, base_cost: usize, back: i32) -> Vec<(S
     for i in -1i32..3 {
         let dir = (s.dir as i32 + i).rem_euclid(4) as usize;
         let pos = s.pos + back * DIRS[dir];
-        let cost = i.abs() as usize * 1000 + 1 + base_cost;
+        let cost = i.unsigned_abs() as usize * 1000 + 1 + base_cost;
         if grid.contains(&pos) {
             next.push((State { pos, dir }, cost));
         }
@@ -44,7 +44,7 @@ fn next_symetrical(grid: &HashSet<IVec2>, s: &State, back: i32) -> Vec<(State, u
     for i in [-1i32, 1, 2] {
         let dir = (s.dir as i32 + i).rem_euclid(4) as usize;
         let next_pos = s.pos + back * DIRS[dir];
-        let cost = i.abs() as usize * 1000;
+        let cost = i.unsigned_abs() as usize * 1000;
         if grid.contains(&next_pos) {
             // We don't skip directly to the pos after rotate + walk
             // We store the state when we only rotated, so that the path can be walked back.
@@ -96,18 +96,12 @@ pub fn part_two(input: &str) -> Option<usize> {
             if !seen.contains(ns) {
                 let fs = from_start.get(ns);
                 let fe = from_end.get(ns);
-                match (fs, fe) {
-                    // (None, None) => unreachable!("{:?} neither in fs or fe", ns),
-                    // (None, Some(_)) => unreachable!("{:?} not in fs", ns),
-                    // (Some(_), None) => unreachable!("{:?} not in fe", ns),
-                    (Some(fs), Some(fe)) => {
-                        if fs.1 + fe.1 == path.1 {
-                            // this is also part of a best path
-                            good.insert(ns.pos);
-                            q.push_back(*ns);
-                        }
+                if let (Some(fs), Some(fe)) = (fs, fe) {
+                    if fs.1 + fe.1 == path.1 {
+                        // this is also part of a best path
+                        good.insert(ns.pos);
+                        q.push_back(*ns);
                     }
-                    (_, _) => {}
                 }
                 seen.insert(*ns);
             }
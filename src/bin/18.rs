@@ -4,13 +4,16 @@ use pathfinding::prelude::bfs;
 
 use glam::IVec2;
 
-advent_of_code::solution!(18);
-
-pub fn part_one(input: &str) -> Option<usize> {
-    part_one_with_size(input, (71, 71), 1024)
-}
+advent_of_code::solution!(
+    18,
+    params {
+        size: (usize, usize) = (71, 71),
+        fallen: usize = 1024
+    }
+);
 
-fn part_one_with_size(input: &str, size: (usize, usize), fallen: usize) -> Option<usize> {
+pub fn part_one(input: &str, params: &Params) -> Option<usize> {
+    let Params { size, fallen } = *params;
     let falling = parse_input(input);
     let mut grid: HashMap<IVec2, bool> = HashMap::new();
     grid.reserve(size.0 * size.1);
@@ -91,7 +94,7 @@ fn mydfs(grid: &HashMap<IVec2, bool>, start: IVec2, end: IVec2) -> Option<usize>
     Some(shortest)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &str, params: &Params) -> Option<u64> {
     None
 }
 
@@ -113,17 +116,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one_with_size(
+        let result = part_one(
             &advent_of_code::template::read_file("examples", DAY),
-            (7, 7),
-            12,
+            &Params::read("examples"),
         );
         assert_eq!(result, Some(22));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", DAY),
+            &Params::read("examples"),
+        );
         assert_eq!(result, None);
     }
 }
@@ -7,7 +7,12 @@ use std::{
 
 use rand::Rng;
 
-advent_of_code::solution!(24);
+advent_of_code::solution!(
+    24,
+    params {
+        z_gates: usize = 46
+    }
+);
 
 #[derive(Debug, Clone)]
 struct Gate {
@@ -152,16 +157,12 @@ enum Op {
     Xor,
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let gates: Vec<_> = (0..=45).map(|i| format!("z{:0>2}", i)).collect();
-    part_one_from_gate(input, &gates)
-}
-
-pub fn part_one_from_gate(input: &str, gates: &[String]) -> Option<usize> {
+pub fn part_one(input: &str, params: &Params) -> Option<usize> {
+    let gates: Vec<_> = (0..params.z_gates).map(|i| format!("z{:0>2}", i)).collect();
     let (inputs, connectivity) = parse_input(input);
     let mut outputs = HashMap::new();
 
-    for gate in gates {
+    for gate in &gates {
         let last_gate = connectivity.get(gate.as_str()).unwrap();
         execute_gate(last_gate, &connectivity, &inputs, &mut outputs, 0);
     }
@@ -209,7 +210,7 @@ fn test_many_ints(input: &str) -> Option<Vec<String>> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+pub fn part_two(input: &str, params: &Params) -> Option<String> {
     test_many_ints(input);
     None
 }
@@ -432,9 +433,9 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one_from_gate(
+        let result = part_one(
             &advent_of_code::template::read_file("examples", DAY),
-            &["z00".to_string(), "z01".to_string(), "z02".to_string()],
+            &Params::read("examples"),
         );
         assert_eq!(result, Some(4));
     }
@@ -0,0 +1,84 @@
+/// Runs a parsed command. Shared by the `advent_of_code` and `solutions` binaries, which only
+/// differ in the solutions compiled into them.
+use advent_of_code::template::{
+    cli,
+    commands::{
+        accept, all, completions, download, examples, leaderboard, read, report, scaffold, solve,
+        status, time,
+    },
+    registry::Solution,
+    Error,
+};
+
+#[cfg(feature = "today")]
+use advent_of_code::template::commands::today;
+
+use crate::args::AppArguments;
+
+/// Run a command. `all`, `time` and `accept` run the days in `solutions` in-process, or each day
+/// in its own process if there are none.
+pub fn run(args: AppArguments, solutions: &[Solution]) -> Result<(), Error> {
+    match args {
+        AppArguments::All {
+            release,
+            isolated,
+            verify,
+            part,
+            timeout,
+            jobs,
+        } => all::handle(solutions, release, isolated, verify, part, timeout, jobs),
+        AppArguments::Accept { day, isolated } => accept::handle(solutions, day, isolated),
+        AppArguments::Time {
+            day,
+            all,
+            store,
+            isolated,
+            bench,
+            compare,
+            part,
+            timeout,
+            history,
+            trend,
+        } => time::handle(
+            solutions, day, all, store, isolated, bench, compare, part, timeout, history, trend,
+        ),
+        AppArguments::Report { format, out } => report::handle(format, out),
+        AppArguments::Status { test } => status::handle(test),
+        AppArguments::Download { day } => download::handle(day),
+        AppArguments::Read { day } => read::handle(day),
+        AppArguments::Leaderboard { path, day, scoring } => {
+            leaderboard::handle(&path, day, scoring)
+        }
+        AppArguments::Examples { day, overwrite } => examples::handle(day, overwrite),
+        AppArguments::Scaffold {
+            day,
+            download,
+            overwrite,
+            template,
+        } => {
+            if download {
+                scaffold::handle_with_download(day, overwrite, &template, &[])?;
+                Ok(())
+            } else {
+                scaffold::handle(day, overwrite, &template)
+            }
+        }
+        AppArguments::Solve {
+            day,
+            release,
+            dhat,
+            options,
+            watch,
+        } => solve::handle(day, release, dhat, &options, watch),
+        #[cfg(feature = "today")]
+        AppArguments::Today { wait } => today::handle(wait),
+        AppArguments::Completions { shell } => completions::handle(shell),
+        AppArguments::Help { command } => {
+            match command {
+                Some(command) => print!("{}", command.help()),
+                None => print!("{}", cli::usage()),
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,29 @@
+/// Runs `all`, `time` and `accept` with every scaffolded day compiled in, so that all days run in
+/// a single process. Started by the `advent_of_code` binary, see `template::registry`.
+use args::parse;
+use std::process;
+
+mod args;
+mod dispatch;
+
+/// Solutions compiled into this binary, see `build.rs`.
+mod solutions {
+    #[cfg(not(feature = "dhat-heap"))]
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+    // every day defines its own global allocator for heap profiling, which can't be combined.
+    #[cfg(feature = "dhat-heap")]
+    pub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[];
+}
+
+fn main() {
+    let args = parse().unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+
+    if let Err(err) = dispatch::run(args, solutions::SOLUTIONS) {
+        eprintln!("Error: {err}");
+        process::exit(err.exit_code());
+    }
+}
@@ -0,0 +1,320 @@
+/// Store of accepted answers, used to verify that solutions still produce correct results.
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    aoc_cli,
+    protocol::{PartRecord, Status},
+    Day, Error, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET,
+};
+
+pub static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// Key used for answers when `AOC_YEAR` is not set.
+static UNKNOWN_YEAR: &str = "unknown";
+
+/// Accepted answers, keyed by year, day and part.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    data: HashMap<String, HashMap<Day, [Option<String>; 2]>>,
+}
+
+/// Outcome of checking a part's answer against the store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+/// Result of verifying a single part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Verification {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub check: Check,
+}
+
+/// The year answers are stored under, taken from `AOC_YEAR`.
+pub fn current_year() -> String {
+    aoc_cli::get_year().map_or_else(|| UNKNOWN_YEAR.into(), |year| year.to_string())
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns an empty store.
+    /// A file that cannot be read or parsed is an error, so that it does not get overwritten.
+    pub fn read_from_file() -> Result<Self, Error> {
+        match fs::read_to_string(ANSWERS_FILE_PATH) {
+            Ok(content) => Answers::try_from(content).map_err(|message| Error::DataFile {
+                path: ANSWERS_FILE_PATH.into(),
+                message,
+            }),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(Error::io("read", ANSWERS_FILE_PATH)(e)),
+        }
+    }
+
+    /// Accepted answer of a part, if one was recorded.
+    pub fn get(&self, year: &str, day: Day, part: u8) -> Option<&str> {
+        let index = part_index(part)?;
+        self.data.get(year)?.get(&day)?[index].as_deref()
+    }
+
+    /// Days of a year that have at least one accepted answer.
+    pub fn days(&self, year: &str) -> Vec<Day> {
+        self.data
+            .get(year)
+            .map(|days| days.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Record `answer` as the accepted answer of a part. Returns the previous answer.
+    pub fn set(&mut self, year: &str, day: Day, part: u8, answer: String) -> Option<String> {
+        let index = part_index(part)?;
+        self.data
+            .entry(year.into())
+            .or_default()
+            .entry(day)
+            .or_default()[index]
+            .replace(answer)
+    }
+
+    /// Check reported part records against the accepted answers.
+    pub fn verify(&self, year: &str, records: &[PartRecord]) -> Vec<Verification> {
+        records
+            .iter()
+            .map(|record| {
+                let check = match (self.get(year, record.day, record.part), &record.answer) {
+                    (None, _) => Check::Missing,
+                    (Some(expected), Some(answer)) if expected == answer => Check::Pass,
+                    (Some(expected), _) => Check::Fail {
+                        expected: expected.into(),
+                    },
+                };
+
+                Verification {
+                    day: record.day,
+                    part: record.part,
+                    answer: record.answer.clone(),
+                    check,
+                }
+            })
+            .collect()
+    }
+}
+
+fn part_index(part: u8) -> Option<usize> {
+    match part {
+        1 | 2 => Some(usize::from(part - 1)),
+        _ => None,
+    }
+}
+
+/// Record the answers of all solved parts as accepted.
+pub fn accept(answers: &mut Answers, records: &[PartRecord]) {
+    let year = current_year();
+
+    for record in records.iter().filter(|r| r.status == Status::Solved) {
+        let Some(answer) = record.answer.clone() else {
+            continue;
+        };
+
+        let label = format!("Day {} Part {}", record.day, record.part);
+
+        match answers.set(&year, record.day, record.part, answer.clone()) {
+            Some(previous) if previous == answer => {}
+            Some(previous) => println!("{label}: {previous} → {ANSI_BOLD}{answer}{ANSI_RESET}"),
+            None => println!("{label}: {ANSI_BOLD}{answer}{ANSI_RESET}"),
+        }
+    }
+}
+
+/// Print a PASS / FAIL / MISSING line per part. Returns `true` if no part failed.
+pub fn print_verification(verifications: &[Verification]) -> bool {
+    for v in verifications {
+        let label = format!("Day {} Part {}", v.day, v.part);
+        let answer = v.answer.as_deref().unwrap_or("✖");
+
+        match &v.check {
+            Check::Pass => println!("{label}: {ANSI_GREEN}PASS{ANSI_RESET}"),
+            Check::Fail { expected } => {
+                println!("{label}: {ANSI_RED}FAIL{ANSI_RESET} (expected {expected}, got {answer})")
+            }
+            Check::Missing => println!("{label}: MISSING"),
+        }
+    }
+
+    !verifications
+        .iter()
+        .any(|v| matches!(v.check, Check::Fail { .. }))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let years = value
+            .data
+            .into_iter()
+            .map(|(year, days)| {
+                let days = days
+                    .into_iter()
+                    .map(|(day, parts)| {
+                        let mut map: HashMap<String, JsonValue> = HashMap::new();
+                        for (i, answer) in parts.into_iter().enumerate() {
+                            if let Some(answer) = answer {
+                                map.insert(format!("part_{}", i + 1), JsonValue::String(answer));
+                            }
+                        }
+                        (day.to_string(), JsonValue::Object(map))
+                    })
+                    .collect();
+                (year, JsonValue::Object(days))
+            })
+            .collect();
+
+        JsonValue::Object(years)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let years = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut answers = Answers::default();
+
+        for (year, days) in years {
+            let days = days
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected answers of a year to be an object.")?;
+
+            for (day, parts) in days {
+                let day = Day::from_str(day).or(Err("Expected answer keys to be days."))?;
+                let parts = parts
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected answers of a day to be an object.")?;
+
+                for part in [1, 2] {
+                    if let Some(answer) = parts.get(&format!("part_{part}")) {
+                        let answer = answer
+                            .get::<String>()
+                            .ok_or("Expected answers to be strings.")?;
+                        answers.set(year, day, part, answer.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Check};
+    use crate::{
+        day,
+        template::{
+            protocol::{PartRecord, Status},
+            stats::BenchStats,
+            Day,
+        },
+    };
+    use tinyjson::JsonValue;
+
+    fn record(day: Day, part: u8, answer: Option<&str>) -> PartRecord {
+        PartRecord {
+            day,
+            part,
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            answer: answer.map(Into::into),
+            stats: BenchStats::default(),
+            heap: None,
+        }
+    }
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "2024": { "05": { "part_1": "6949", "part_2": "4145" }, "17": { "part_1": "4,6,3" } } }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get("2024", day!(5), 1), Some("6949"));
+        assert_eq!(answers.get("2024", day!(5), 2), Some("4145"));
+        assert_eq!(answers.get("2024", day!(17), 1), Some("4,6,3"));
+        assert_eq!(answers.get("2024", day!(17), 2), None);
+        assert_eq!(answers.get("2023", day!(5), 1), None);
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let mut answers = Answers::default();
+        answers.set("2024", day!(3), 2, "48".into());
+        let json = JsonValue::from(answers.clone()).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "2024": { "26": { "part_1": "1" } } }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn verifies_records() {
+        let mut answers = Answers::default();
+        answers.set("2024", day!(1), 1, "42".into());
+        answers.set("2024", day!(1), 2, "43".into());
+        answers.set("2024", day!(2), 1, "7".into());
+
+        let verifications = answers.verify(
+            "2024",
+            &[
+                record(day!(1), 1, Some("42")),
+                record(day!(1), 2, Some("44")),
+                record(day!(2), 1, None),
+                record(day!(2), 2, Some("1")),
+            ],
+        );
+
+        assert_eq!(verifications[0].check, Check::Pass);
+        assert_eq!(
+            verifications[1].check,
+            Check::Fail {
+                expected: "43".into()
+            }
+        );
+        assert_eq!(
+            verifications[2].check,
+            Check::Fail {
+                expected: "7".into()
+            }
+        );
+        assert_eq!(verifications[3].check, Check::Missing);
+    }
+}
@@ -1,6 +1,7 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    io::{self, Write},
     process::{Command, Output, Stdio},
 };
 
@@ -8,19 +9,34 @@ use crate::template::Day;
 
 #[derive(Debug)]
 pub enum AocCommandError {
-    CommandNotFound,
-    CommandNotCallable,
+    /// `aoc -V` could not be run, with the error of starting it.
+    CommandNotFound(io::Error),
+    /// aoc-cli could not be started, with the error of starting it.
+    CommandNotCallable(io::Error),
     BadExitStatus(Output),
 }
 
+impl std::error::Error for AocCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocCommandError::CommandNotFound(e) | AocCommandError::CommandNotCallable(e) => Some(e),
+            AocCommandError::BadExitStatus(_) => None,
+        }
+    }
+}
+
 impl Display for AocCommandError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AocCommandError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-            AocCommandError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-            AocCommandError::BadExitStatus(_) => {
-                write!(f, "aoc-cli exited with a non-zero status.")
-            }
+            AocCommandError::CommandNotFound(_) => write!(
+                f,
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            ),
+            AocCommandError::CommandNotCallable(e) => write!(f, "aoc-cli could not be called: {e}"),
+            AocCommandError::BadExitStatus(output) => match output.status.code() {
+                Some(code) => write!(f, "aoc-cli exited with status {code}."),
+                None => write!(f, "aoc-cli was terminated."),
+            },
         }
     }
 }
@@ -29,7 +45,7 @@ pub fn check() -> Result<(), AocCommandError> {
     Command::new("aoc")
         .arg("-V")
         .output()
-        .map_err(|_| AocCommandError::CommandNotFound)?;
+        .map_err(AocCommandError::CommandNotFound)?;
     Ok(())
 }
 
@@ -77,18 +93,19 @@ pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandErro
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+    call_aoc_cli_captured(&args)
 }
 
 fn get_input_path(day: Day) -> String {
     format!("data/inputs/{day}.txt")
 }
 
-fn get_puzzle_path(day: Day) -> String {
+pub fn get_puzzle_path(day: Day) -> String {
     format!("data/puzzles/{day}.md")
 }
 
-fn get_year() -> Option<u16> {
+/// The puzzle year configured via `AOC_YEAR`.
+pub fn get_year() -> Option<u16> {
     match std::env::var("AOC_YEAR") {
         Ok(x) => x.parse().ok().or(None),
         Err(_) => None,
@@ -115,7 +132,25 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .output()
-        .map_err(|_| AocCommandError::CommandNotCallable)?;
+        .map_err(AocCommandError::CommandNotCallable)?;
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
+}
+
+/// Like `call_aoc_cli`, but captures the output so it can be inspected. The output is still
+/// forwarded to the terminal afterwards.
+fn call_aoc_cli_captured(args: &[String]) -> Result<Output, AocCommandError> {
+    let output = Command::new("aoc")
+        .args(args)
+        .output()
+        .map_err(AocCommandError::CommandNotCallable)?;
+
+    let _ = io::stdout().write_all(&output.stdout);
+    let _ = io::stderr().write_all(&output.stderr);
 
     if output.status.success() {
         Ok(output)
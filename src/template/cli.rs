@@ -0,0 +1,751 @@
+/// Help texts and shell completions for the commands of the `advent_of_code` binary.
+///
+/// Commands are invoked through the cargo aliases in `.cargo/config.toml`, e.g. `cargo solve 01`.
+/// [`COMMANDS`] describes their arguments and is used to print help, to reject unknown flags and
+/// to generate completion scripts for `cargo`.
+use std::{fmt::Write, str::FromStr};
+
+use crate::template::{all_days, scaffolds};
+
+/// The free-standing argument of a command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Positional {
+    None,
+    Day,
+    OptionalDay,
+    File,
+    Shell,
+    Command,
+}
+
+impl Positional {
+    fn usage(self) -> &'static str {
+        match self {
+            Positional::None => "",
+            Positional::Day => " <DAY>",
+            Positional::OptionalDay => " [DAY]",
+            Positional::File => " <FILE>",
+            Positional::Shell => " <SHELL>",
+            Positional::Command => " [COMMAND]",
+        }
+    }
+}
+
+/// Values an option accepts, used for completions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Values {
+    Any,
+    Days,
+    File,
+    List(&'static [&'static str]),
+    Templates,
+}
+
+/// A flag or option of a command.
+#[derive(Debug)]
+pub struct Flag {
+    pub long: &'static str,
+    pub short: Option<&'static str>,
+    /// Name and possible values of the value, for options that take one.
+    pub value: Option<(&'static str, Values)>,
+    pub help: &'static str,
+}
+
+/// A command of the binary.
+#[derive(Debug)]
+pub struct Command {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub positional: Positional,
+    pub flags: &'static [Flag],
+}
+
+const fn flag(long: &'static str, help: &'static str) -> Flag {
+    Flag {
+        long,
+        short: None,
+        value: None,
+        help,
+    }
+}
+
+const fn option(
+    long: &'static str,
+    name: &'static str,
+    values: Values,
+    help: &'static str,
+) -> Flag {
+    Flag {
+        long,
+        short: None,
+        value: Some((name, values)),
+        help,
+    }
+}
+
+const PARTS: Values = Values::List(&["1", "2"]);
+
+const HELP: Flag = Flag {
+    long: "--help",
+    short: Some("-h"),
+    value: None,
+    help: "Print the help of this command",
+};
+
+pub const COMMANDS: &[Command] = &[
+    Command {
+        name: "scaffold",
+        about: "Create the solution and data files of a day",
+        positional: Positional::Day,
+        flags: &[
+            flag(
+                "--download",
+                "Also download the input and puzzle description",
+            ),
+            flag("--overwrite", "Overwrite an existing solution file"),
+            option(
+                "--template",
+                "NAME",
+                Values::Templates,
+                "Template to create the solution from",
+            ),
+        ],
+    },
+    Command {
+        name: "download",
+        about: "Download the input and puzzle description of a day",
+        positional: Positional::Day,
+        flags: &[],
+    },
+    Command {
+        name: "read",
+        about: "Read the puzzle description of a day in the terminal",
+        positional: Positional::Day,
+        flags: &[],
+    },
+    Command {
+        name: "examples",
+        about: "Extract the examples and expected answers from the puzzle description",
+        positional: Positional::Day,
+        flags: &[flag("--overwrite", "Overwrite existing example files")],
+    },
+    Command {
+        name: "solve",
+        about: "Run the solution of a day",
+        positional: Positional::Day,
+        flags: &[
+            flag("--release", "Build with optimizations"),
+            flag("--dhat", "Profile heap allocations"),
+            option("--part", "PART", PARTS, "Only run one part"),
+            option(
+                "--input",
+                "FILE",
+                Values::File,
+                "Read the input from a file, or from stdin with -",
+            ),
+            flag("--example", "Run against the example"),
+            option(
+                "--example-part",
+                "N",
+                Values::Any,
+                "Run against an additional example file",
+            ),
+            option(
+                "--param",
+                "NAME=VALUE",
+                Values::Any,
+                "Override a solution parameter",
+            ),
+            option("--submit", "PART", PARTS, "Submit the answer of a part"),
+            flag("--verify", "Compare the answers with the accepted answers"),
+            flag(
+                "--watch",
+                "Rerun whenever the solution or its inputs change",
+            ),
+            flag("--test", "Also run the example tests when watching"),
+        ],
+    },
+    Command {
+        name: "all",
+        about: "Run the solutions of all days",
+        positional: Positional::None,
+        flags: &[
+            flag("--release", "Build with optimizations"),
+            flag("--isolated", "Run every day in its own process"),
+            flag("--verify", "Compare the answers with the accepted answers"),
+            option("--part", "PART", PARTS, "Only run one part"),
+            option(
+                "--timeout",
+                "SECONDS",
+                Values::Any,
+                "Time limit per day, runs each day in its own process",
+            ),
+            Flag {
+                long: "--jobs",
+                short: Some("-j"),
+                value: Some(("N", Values::Any)),
+                help: "Number of days to run at the same time",
+            },
+        ],
+    },
+    Command {
+        name: "accept",
+        about: "Store the current answers as accepted answers",
+        positional: Positional::OptionalDay,
+        flags: &[flag("--isolated", "Run every day in its own process")],
+    },
+    Command {
+        name: "time",
+        about: "Benchmark solutions",
+        positional: Positional::OptionalDay,
+        flags: &[
+            flag(
+                "--all",
+                "Bench all days, including the ones with stored timings",
+            ),
+            flag("--store", "Store the timings and update the readme"),
+            flag("--isolated", "Run every day in its own process"),
+            option("--part", "PART", PARTS, "Only bench one part"),
+            option(
+                "--timeout",
+                "SECONDS",
+                Values::Any,
+                "Time limit per day, runs each day in its own process",
+            ),
+            option("--warmup", "N", Values::Any, "Number of warm-up iterations"),
+            option(
+                "--budget",
+                "MS",
+                Values::Any,
+                "Time to spend sampling each part",
+            ),
+            option(
+                "--min-samples",
+                "N",
+                Values::Any,
+                "Minimum number of samples per part",
+            ),
+            option(
+                "--max-samples",
+                "N",
+                Values::Any,
+                "Maximum number of samples per part",
+            ),
+            flag("--compare", "Compare against the stored timings"),
+            option(
+                "--threshold",
+                "PERCENT",
+                Values::Any,
+                "Slowdown that counts as a regression",
+            ),
+            flag(
+                "--history",
+                "Print the recorded timings instead of benching",
+            ),
+            flag("--trend", "Add a trend column to the readme, with --store"),
+        ],
+    },
+    Command {
+        name: "report",
+        about: "Export answers and timings",
+        positional: Positional::None,
+        flags: &[
+            option(
+                "--format",
+                "FORMAT",
+                Values::List(&["md", "csv", "json", "html"]),
+                "Format of the report",
+            ),
+            option("--out", "FILE", Values::File, "Write the report to a file"),
+        ],
+    },
+    Command {
+        name: "status",
+        about: "Show a calendar of the progress of each day",
+        positional: Positional::None,
+        flags: &[flag("--test", "Also run the example tests of each day")],
+    },
+    Command {
+        name: "leaderboard",
+        about: "View the JSON export of a private leaderboard",
+        positional: Positional::File,
+        flags: &[
+            option(
+                "--day",
+                "DAY",
+                Values::Days,
+                "Show the stars of a single day",
+            ),
+            option(
+                "--scoring",
+                "SCORING",
+                Values::List(&["local", "global"]),
+                "How members are ranked",
+            ),
+        ],
+    },
+    Command {
+        name: "today",
+        about: "Scaffold, download and read the puzzle of today",
+        positional: Positional::None,
+        flags: &[flag("--wait", "Wait for the next puzzle to unlock")],
+    },
+    Command {
+        name: "completions",
+        about: "Print a completion script for a shell",
+        positional: Positional::Shell,
+        flags: &[],
+    },
+    Command {
+        name: "help",
+        about: "Print the help of a command",
+        positional: Positional::Command,
+        flags: &[],
+    },
+];
+
+/// Find a command by name.
+pub fn find(name: &str) -> Option<&'static Command> {
+    COMMANDS.iter().find(|command| command.name == name)
+}
+
+impl Flag {
+    /// The short and long name, separated by `separator`.
+    fn names(&self, separator: &str) -> String {
+        match self.short {
+            Some(short) => format!("{short}{separator}{}", self.long),
+            None => self.long.to_string(),
+        }
+    }
+}
+
+impl Command {
+    fn all_flags(&self) -> impl Iterator<Item = &Flag> {
+        self.flags.iter().chain([&HELP])
+    }
+
+    fn find_flag(&self, arg: &str) -> Option<&Flag> {
+        self.all_flags()
+            .find(|flag| flag.long == arg || flag.short == Some(arg))
+    }
+
+    /// Check that all flags in `args` belong to this command.
+    /// Values of options are skipped, so that e.g. `--input -` is accepted.
+    pub fn check_flags(&self, args: &[String]) -> Result<(), String> {
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match self.find_flag(arg) {
+                Some(Flag { value: Some(_), .. }) => {
+                    args.next();
+                }
+                Some(_) => {}
+                None if arg.starts_with('-') && arg != "-" => {
+                    return Err(format!("unknown option `{arg}` for `{}`.", self.name));
+                }
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Help text of the command, as printed by `cargo <command> --help`.
+    pub fn help(&self) -> String {
+        let mut help = format!(
+            "{}.\n\nUsage: cargo {}{}",
+            self.about,
+            self.name,
+            self.positional.usage()
+        );
+        help += " [OPTIONS]\n\nOptions:\n";
+
+        let rows: Vec<(String, &str)> = self
+            .all_flags()
+            .map(|flag| {
+                let mut name = flag
+                    .short
+                    .map_or(String::new(), |short| format!("{short}, "));
+                name += flag.long;
+                if let Some((value, _)) = flag.value {
+                    let _ = write!(name, " <{value}>");
+                }
+                (name, flag.help)
+            })
+            .collect();
+
+        let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        for (name, text) in rows {
+            let _ = writeln!(help, "  {name:width$}  {text}");
+        }
+
+        help
+    }
+}
+
+/// Summary of all commands, as printed when no command is given.
+pub fn usage() -> String {
+    let mut usage = String::from("Usage: cargo <COMMAND> [OPTIONS]\n\nCommands:\n");
+
+    let width = COMMANDS.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    for command in COMMANDS {
+        let _ = writeln!(usage, "  {:width$}  {}", command.name, command.about);
+    }
+
+    usage + "\nRun `cargo <COMMAND> --help` for the options of a command.\n"
+}
+
+/// A shell to generate completions for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl FromStr for Shell {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Shell::Bash),
+            "zsh" => Ok(Shell::Zsh),
+            "fish" => Ok(Shell::Fish),
+            _ => Err(format!("unknown shell `{s}`, expected bash, zsh or fish.")),
+        }
+    }
+}
+
+/// Completion script for the cargo aliases of the template.
+///
+/// The scripts only handle the commands of the template and hand everything else to the
+/// completions of `cargo` itself, if they are installed.
+pub fn completions(shell: Shell) -> String {
+    let days: Vec<String> = all_days().map(|day| day.to_string()).collect();
+    let templates = scaffolds::names();
+    let words = Words {
+        days: days.join(" "),
+        templates: templates.join(" "),
+        commands: COMMANDS
+            .iter()
+            .map(|c| c.name)
+            .collect::<Vec<_>>()
+            .join(" "),
+    };
+
+    match shell {
+        Shell::Bash => bash(&words),
+        Shell::Zsh => zsh(&words),
+        Shell::Fish => fish(&words),
+    }
+}
+
+/// Space-separated completion candidates.
+struct Words {
+    days: String,
+    templates: String,
+    commands: String,
+}
+
+impl Words {
+    fn values(&self, values: Values) -> Option<String> {
+        match values {
+            Values::Any | Values::File => None,
+            Values::Days => Some(self.days.clone()),
+            Values::List(list) => Some(list.join(" ")),
+            Values::Templates => Some(self.templates.clone()),
+        }
+    }
+
+    fn positional(&self, positional: Positional) -> Option<String> {
+        match positional {
+            Positional::None | Positional::File => None,
+            Positional::Day | Positional::OptionalDay => Some(self.days.clone()),
+            Positional::Shell => Some("bash zsh fish".into()),
+            Positional::Command => Some(self.commands.clone()),
+        }
+    }
+}
+
+fn bash(words: &Words) -> String {
+    let mut script = String::from(
+        "# Completions for the cargo aliases of the advent of code template.\n\
+        _aoc_template() {\n    \
+            local cur=\"${COMP_WORDS[COMP_CWORD]}\" prev=\"${COMP_WORDS[COMP_CWORD-1]}\"\n    \
+            local opts=\"\" words=\"\" files=0\n    \
+            COMPREPLY=()\n\n    \
+            case \"${COMP_WORDS[1]}\" in\n",
+    );
+
+    for command in COMMANDS {
+        let _ = writeln!(script, "        {})", command.name);
+
+        let options: Vec<&Flag> = command.all_flags().filter(|f| f.value.is_some()).collect();
+        if !options.is_empty() {
+            script += "            case \"$prev\" in\n";
+            for option in options {
+                let (_, values) = option.value.unwrap_or(("", Values::Any));
+                let reply = match (values, words.values(values)) {
+                    (Values::File, _) => "COMPREPLY=($(compgen -f -- \"$cur\"))".to_string(),
+                    (_, Some(list)) => format!("COMPREPLY=($(compgen -W \"{list}\" -- \"$cur\"))"),
+                    (_, None) => "COMPREPLY=()".to_string(),
+                };
+                let _ = writeln!(
+                    script,
+                    "                {}) {reply}; return ;;",
+                    option.names("|")
+                );
+            }
+            script += "            esac\n";
+        }
+
+        let flags: Vec<String> = command.all_flags().map(|f| f.names(" ")).collect();
+        let _ = writeln!(script, "            opts=\"{}\"", flags.join(" "));
+        match words.positional(command.positional) {
+            Some(list) => {
+                let _ = writeln!(script, "            words=\"{list}\"");
+            }
+            None if command.positional == Positional::File => {
+                script += "            files=1\n";
+            }
+            None => {}
+        }
+        script += "            ;;\n";
+    }
+
+    let _ = write!(
+        script,
+        "        *)\n            \
+                if declare -F _cargo >/dev/null; then\n                \
+                    _cargo \"$@\"\n            \
+                fi\n            \
+                if [[ $COMP_CWORD -eq 1 ]]; then\n                \
+                    COMPREPLY+=($(compgen -W \"{}\" -- \"$cur\"))\n            \
+                fi\n            \
+                return\n            \
+                ;;\n    \
+            esac\n\n    \
+            if [[ \"$cur\" == -* ]]; then\n        \
+                COMPREPLY=($(compgen -W \"$opts\" -- \"$cur\"))\n    \
+            elif [[ $files -eq 1 ]]; then\n        \
+                COMPREPLY=($(compgen -f -- \"$cur\"))\n    \
+            else\n        \
+                COMPREPLY=($(compgen -W \"$words\" -- \"$cur\"))\n    \
+            fi\n\
+        }}\n\
+        complete -o default -F _aoc_template cargo\n",
+        words.commands
+    );
+
+    script
+}
+
+fn zsh(words: &Words) -> String {
+    let mut script = String::from(
+        "# Completions for the cargo aliases of the advent of code template.\n\
+        _aoc_template() {\n    \
+            local -a commands=(\n",
+    );
+
+    for command in COMMANDS {
+        let _ = writeln!(script, "        '{}:{}'", command.name, command.about);
+    }
+
+    script += "    )\n\n    \
+        if (( CURRENT == 2 )); then\n        \
+            _describe 'template command' commands\n        \
+            (( $+functions[_cargo] )) && _cargo \"$@\"\n        \
+            return\n    \
+        fi\n\n    \
+        case $words[2] in\n";
+
+    for command in COMMANDS {
+        let mut specs: Vec<String> = command
+            .all_flags()
+            .map(|flag| {
+                let names = match flag.short {
+                    Some(short) => {
+                        format!("({short} {long})'{{{short},{long}}}'", long = flag.long)
+                    }
+                    None => flag.long.to_string(),
+                };
+                let value = match flag.value {
+                    None => String::new(),
+                    Some((name, values)) => match (values, words.values(values)) {
+                        (Values::File, _) => format!(":{name}:_files"),
+                        (_, Some(list)) => format!(":{name}:({list})"),
+                        (_, None) => format!(":{name}: "),
+                    },
+                };
+                format!("'{names}[{}]{value}'", flag.help)
+            })
+            .collect();
+
+        let list = words.positional(command.positional).unwrap_or_default();
+        match command.positional {
+            Positional::None => {}
+            Positional::Day => specs.push(format!("':day:({list})'")),
+            Positional::OptionalDay => specs.push(format!("'::day:({list})'")),
+            Positional::File => specs.push("':file:_files'".into()),
+            Positional::Shell => specs.push(format!("':shell:({list})'")),
+            Positional::Command => specs.push(format!("'::command:({list})'")),
+        }
+
+        let _ = writeln!(
+            script,
+            "        {})\n            shift words; (( CURRENT-- ))\n            _arguments \\\n                {}\n            ;;",
+            command.name,
+            specs.join(" \\\n                ")
+        );
+    }
+
+    script += "        *)\n            \
+                (( $+functions[_cargo] )) && _cargo \"$@\"\n            \
+                ;;\n    \
+            esac\n\
+        }\n\
+        compdef _aoc_template cargo\n";
+
+    script
+}
+
+fn fish(words: &Words) -> String {
+    let mut script =
+        String::from("# Completions for the cargo aliases of the advent of code template.\n");
+
+    for command in COMMANDS {
+        let _ = writeln!(
+            script,
+            "complete -c cargo -n \"__fish_use_subcommand\" -f -a {} -d \"{}\"",
+            command.name, command.about
+        );
+    }
+
+    for command in COMMANDS {
+        let condition = format!("-n \"__fish_seen_subcommand_from {}\"", command.name);
+
+        match (command.positional, words.positional(command.positional)) {
+            (Positional::File, _) => {
+                let _ = writeln!(script, "complete -c cargo {condition} -F");
+            }
+            (_, Some(list)) => {
+                let _ = writeln!(script, "complete -c cargo {condition} -f -a \"{list}\"");
+            }
+            _ => {}
+        }
+
+        for flag in command.all_flags() {
+            let mut line = format!(
+                "complete -c cargo {condition} -l {}",
+                flag.long.trim_start_matches('-')
+            );
+            if let Some(short) = flag.short {
+                let _ = write!(line, " -s {}", short.trim_start_matches('-'));
+            }
+            if let Some((_, values)) = flag.value {
+                match (values, words.values(values)) {
+                    (Values::File, _) => line += " -r -F",
+                    (_, Some(list)) => {
+                        let _ = write!(line, " -x -a \"{list}\"");
+                    }
+                    (_, None) => line += " -x",
+                }
+            }
+            let _ = writeln!(script, "{line} -d \"{}\"", flag.help);
+        }
+    }
+
+    script
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{completions, find, usage, Shell, COMMANDS};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn rejects_unknown_flags() {
+        let solve = find("solve").unwrap();
+
+        assert_eq!(
+            solve.check_flags(&args(&["01", "--release", "--part", "2"])),
+            Ok(())
+        );
+        // values of options are not flags.
+        assert_eq!(solve.check_flags(&args(&["01", "--input", "-"])), Ok(()));
+        assert_eq!(solve.check_flags(&args(&["01", "--help"])), Ok(()));
+        assert_eq!(
+            solve.check_flags(&args(&["01", "--relase"])),
+            Err("unknown option `--relase` for `solve`.".into())
+        );
+
+        // flags are only valid for the commands that accept them.
+        let download = find("download").unwrap();
+        assert!(download.check_flags(&args(&["01", "--release"])).is_err());
+    }
+
+    #[test]
+    fn prints_help() {
+        let help = find("all").unwrap().help();
+
+        assert!(help.starts_with("Run the solutions of all days.\n\nUsage: cargo all [OPTIONS]"));
+        assert!(help.contains("  -j, --jobs <N>  "));
+        assert!(help.contains("  -h, --help  "));
+
+        let help = find("solve").unwrap().help();
+        assert!(help.contains("Usage: cargo solve <DAY> [OPTIONS]"));
+        assert!(help.contains("--submit <PART>"));
+    }
+
+    #[test]
+    fn lists_all_commands() {
+        let usage = usage();
+
+        for command in COMMANDS {
+            assert!(usage.contains(command.name));
+        }
+    }
+
+    #[test]
+    fn help_texts_are_safe_to_quote() {
+        // help texts are embedded in quoted strings of the completion scripts.
+        for command in COMMANDS {
+            let texts = command.flags.iter().map(|f| f.help).chain([command.about]);
+            for text in texts {
+                assert!(
+                    !text.contains(['\'', '"', '[', ']', ':', '$', '`']),
+                    "{text}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn generates_completions() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let script = completions(shell);
+
+            assert!(script.contains("01 02 03"), "{shell:?}");
+            assert!(script.contains("25"), "{shell:?}");
+            assert!(script.contains("blank"), "{shell:?}");
+            assert!(script.contains("example-part"), "{shell:?}");
+        }
+
+        assert!(completions(Shell::Bash).contains("complete -o default -F _aoc_template cargo"));
+        assert!(completions(Shell::Zsh).contains("compdef _aoc_template cargo"));
+        assert!(completions(Shell::Fish).contains(
+            "complete -c cargo -n \"__fish_seen_subcommand_from solve\" -l part -x -a \"1 2\""
+        ));
+    }
+
+    #[test]
+    fn parses_shells() {
+        assert_eq!("zsh".parse(), Ok(Shell::Zsh));
+        assert!("powershell".parse::<Shell>().is_err());
+    }
+}
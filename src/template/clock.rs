@@ -0,0 +1,283 @@
+/// Time keeping for `cargo today --wait`, which waits for the next puzzle to unlock.
+///
+/// The current time is read through the [`Clock`] trait, so that waiting can be tested with a
+/// fake clock. Dates are computed on unix timestamps and do not need the `chrono` dependency.
+use std::{
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::Day;
+
+/// Puzzles unlock at midnight EST, which is 05:00 UTC.
+pub const UNLOCK_HOUR_UTC: u64 = 5;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Delays between download attempts right after an unlock, about a minute in total.
+/// The puzzle can take a few seconds to become available to everyone.
+pub const RETRY_DELAYS: [Duration; 5] = [
+    Duration::from_secs(2),
+    Duration::from_secs(4),
+    Duration::from_secs(8),
+    Duration::from_secs(16),
+    Duration::from_secs(30),
+];
+
+/// Source of the current time.
+pub trait Clock {
+    /// Seconds since the unix epoch.
+    fn now(&self) -> u64;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The system clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs())
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// The unlock of a puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unlock {
+    pub year: i64,
+    pub day: Day,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// Seconds since the unix epoch at which the puzzle of a day unlocks.
+pub fn unlock_timestamp(year: i64, day: Day) -> Option<u64> {
+    let days = days_from_civil(year, 12, i64::from(day.into_inner()));
+    Some(u64::try_from(days).ok()? * SECONDS_PER_DAY + UNLOCK_HOUR_UTC * 3600)
+}
+
+/// The first puzzle that unlocks after `now`.
+pub fn next_unlock(now: u64) -> Unlock {
+    // dates change at the unlock, i.e. the date in UTC-5 is the day of the last unlocked puzzle.
+    let days = (now.saturating_sub(UNLOCK_HOUR_UTC * 3600) / SECONDS_PER_DAY) as i64;
+    let (year, month, day) = civil_from_days(days);
+
+    let (year, day) = match (month, day) {
+        (12, day) if day < 25 => (year, day + 1),
+        (12, _) => (year + 1, 1),
+        _ => (year, 1),
+    };
+
+    // NOTE: the day is in range 1 to 25 by construction.
+    let day = Day::new(u8::try_from(day).unwrap_or(1)).unwrap();
+
+    Unlock {
+        year,
+        day,
+        timestamp: unlock_timestamp(year, day).unwrap_or_default(),
+    }
+}
+
+/// Sleep until `unlock`, calling `tick` with the remaining seconds about once per second.
+pub fn wait_for(clock: &impl Clock, unlock: &Unlock, mut tick: impl FnMut(u64)) {
+    loop {
+        let now = clock.now();
+        if now >= unlock.timestamp {
+            return;
+        }
+
+        let remaining = unlock.timestamp - now;
+        tick(remaining);
+        clock.sleep(Duration::from_secs(1));
+    }
+}
+
+/// Call `f` until it succeeds, sleeping for each of `delays` in turn between attempts.
+/// `on_retry` is called with the error and the delay before sleeping. If every attempt fails,
+/// the error of the last one is returned.
+pub fn retry<T, E>(
+    clock: &impl Clock,
+    delays: &[Duration],
+    mut f: impl FnMut() -> Result<T, E>,
+    mut on_retry: impl FnMut(&E, Duration),
+) -> Result<T, E> {
+    for delay in delays {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                on_retry(&e, *delay);
+                clock.sleep(*delay);
+            }
+        }
+    }
+
+    f()
+}
+
+/// Format a number of seconds as `HH:MM:SS`, prefixed with the number of days if longer than a day.
+pub fn format_seconds(seconds: u64) -> String {
+    let (days, rest) = (seconds / SECONDS_PER_DAY, seconds % SECONDS_PER_DAY);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        rest / 3600,
+        rest % 3600 / 60,
+        rest % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+/// Days since the unix epoch of a date in the proleptic gregorian calendar,
+/// see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+pub fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Date as `(year, month, day)` of a number of days since the unix epoch,
+/// see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+pub fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    (yoe + era * 400 + i64::from(month <= 2), month, day)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        civil_from_days, days_from_civil, format_seconds, next_unlock, retry, unlock_timestamp,
+        wait_for, Clock, RETRY_DELAYS,
+    };
+    use crate::day;
+    use std::{cell::Cell, time::Duration};
+
+    /// A clock that advances by the duration it is asked to sleep.
+    struct FakeClock {
+        now: Cell<u64>,
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> u64 {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration.as_secs());
+        }
+    }
+
+    /// 2024-12-01 05:00:00 UTC
+    const DEC_1_2024: u64 = 1_733_029_200;
+
+    #[test]
+    fn converts_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2024, 12, 1), 20_058);
+        assert_eq!(civil_from_days(20_058), (2024, 12, 1));
+        assert_eq!(civil_from_days(days_from_civil(2000, 2, 29)), (2000, 2, 29));
+    }
+
+    #[test]
+    fn computes_unlocks() {
+        assert_eq!(unlock_timestamp(2024, day!(1)), Some(DEC_1_2024));
+
+        // before december, the first day of this year is next.
+        let unlock = next_unlock(DEC_1_2024 - 30 * 86_400);
+        assert_eq!((unlock.year, unlock.day), (2024, day!(1)));
+        assert_eq!(unlock.timestamp, DEC_1_2024);
+
+        // a second before the unlock, it is still the 30th of november in UTC-5.
+        assert_eq!(next_unlock(DEC_1_2024 - 1).day, day!(1));
+
+        // right at the unlock, the next puzzle is the one of tomorrow.
+        let unlock = next_unlock(DEC_1_2024);
+        assert_eq!((unlock.year, unlock.day), (2024, day!(2)));
+        assert_eq!(unlock.timestamp, DEC_1_2024 + 86_400);
+
+        // after the 25th, the next puzzle unlocks next year.
+        let unlock = next_unlock(DEC_1_2024 + 24 * 86_400 + 60);
+        assert_eq!((unlock.year, unlock.day), (2025, day!(1)));
+    }
+
+    #[test]
+    fn waits_for_unlocks() {
+        let clock = FakeClock {
+            now: Cell::new(DEC_1_2024 - 3),
+        };
+
+        let mut ticks = vec![];
+        wait_for(&clock, &next_unlock(clock.now()), |remaining| {
+            ticks.push(remaining)
+        });
+
+        assert_eq!(ticks, vec![3, 2, 1]);
+        assert_eq!(clock.now(), DEC_1_2024);
+
+        // does not wait if the puzzle already unlocked.
+        let unlock = next_unlock(DEC_1_2024 - 3);
+        wait_for(&clock, &unlock, |_| panic!("should not wait"));
+    }
+
+    #[test]
+    fn retries_with_backoff() {
+        let clock = FakeClock { now: Cell::new(0) };
+
+        let mut attempts = 0;
+        let result = retry(
+            &clock,
+            &RETRY_DELAYS,
+            || {
+                attempts += 1;
+                if attempts < 3 {
+                    Err(attempts)
+                } else {
+                    Ok(attempts)
+                }
+            },
+            |_, _| {},
+        );
+        assert_eq!(result, Ok(3));
+        assert_eq!(clock.now(), 2 + 4);
+
+        // gives up after about a minute.
+        let mut retries = vec![];
+        let result: Result<(), _> = retry(
+            &clock,
+            &RETRY_DELAYS,
+            || Err("not yet"),
+            |_, delay| retries.push(delay.as_secs()),
+        );
+        assert_eq!(result, Err("not yet"));
+        assert_eq!(retries, vec![2, 4, 8, 16, 30]);
+        assert_eq!(clock.now(), 6 + 60);
+    }
+
+    #[test]
+    fn formats_seconds() {
+        assert_eq!(format_seconds(59), "00:00:59");
+        assert_eq!(format_seconds(3 * 3600 + 62), "03:01:02");
+        assert_eq!(format_seconds(86_400 + 1), "1d 00:00:01");
+    }
+}
@@ -0,0 +1,28 @@
+use std::collections::HashSet;
+
+use crate::template::{
+    all_days,
+    answers::{self, Answers, ANSWERS_FILE_PATH},
+    registry::Solution,
+    run_multi::{run_multi, Mode, Timeouts},
+    runner::RunOptions,
+    Day, Error,
+};
+
+pub fn handle(solutions: &[Solution], day: Option<Day>, isolated: bool) -> Result<(), Error> {
+    let days_to_run = day.map_or_else(|| all_days().collect(), |day| HashSet::from([day]));
+
+    // read the answers before running, a file that cannot be read must not be overwritten.
+    let mut answers = Answers::read_from_file()?;
+
+    let mode = Mode::select(solutions, isolated, true, Timeouts::default());
+    let run = run_multi(&days_to_run, &mode, &RunOptions::default());
+
+    println!();
+    answers::accept(&mut answers, &run.records());
+    answers
+        .store_file()
+        .map_err(Error::io("write", ANSWERS_FILE_PATH))?;
+    println!("Stored accepted answers.");
+    Ok(())
+}
@@ -1,5 +1,64 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    all_days,
+    answers::{current_year, print_verification, Answers, Check},
+    registry::Solution,
+    run_multi::{run_multi, DayStatus, Mode, Timeouts},
+    runner::RunOptions,
+    Error,
+};
+
+pub fn handle(
+    solutions: &[Solution],
+    is_release: bool,
+    isolated: bool,
+    verify: bool,
+    part: Option<u8>,
+    timeout: Option<Duration>,
+    jobs: Option<usize>,
+) -> Result<(), Error> {
+    let timeouts =
+        Timeouts::from_env(timeout).map_err(|e| Error::Config(format!("invalid timeout: {e}")))?;
+
+    // fail before running if the accepted answers can't be read.
+    let answers = Answers::read_from_file()?;
+
+    let mode = match jobs {
+        Some(jobs) => Mode::Parallel {
+            is_release,
+            timeouts,
+            jobs,
+        },
+        None => Mode::select(solutions, isolated, is_release, timeouts),
+    };
+    let options = RunOptions {
+        part,
+        ..RunOptions::default()
+    };
+    let mut run = run_multi(&all_days().collect(), &mode, &options);
+
+    // a day that ran fine can still produce an answer that differs from the accepted one.
+    let verifications = answers.verify(&current_year(), &run.records());
+    for day_run in run.days.iter_mut().filter(|r| r.status == DayStatus::Ok) {
+        if verifications
+            .iter()
+            .any(|v| v.day == day_run.day && matches!(v.check, Check::Fail { .. }))
+        {
+            day_run.status = DayStatus::WrongAnswer;
+        }
+    }
+
+    if verify {
+        println!();
+        print_verification(&verifications);
+    }
+
+    println!();
+    run.print_summary();
+
+    match run.failures() {
+        0 => Ok(()),
+        failures => Err(Error::DaysFailed(failures)),
+    }
 }
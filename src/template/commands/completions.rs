@@ -0,0 +1,9 @@
+use crate::template::{
+    cli::{self, Shell},
+    Error,
+};
+
+pub fn handle(shell: Shell) -> Result<(), Error> {
+    print!("{}", cli::completions(shell));
+    Ok(())
+}
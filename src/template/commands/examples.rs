@@ -0,0 +1,62 @@
+use std::fs;
+
+use crate::template::{
+    aoc_cli::get_puzzle_path,
+    puzzle::{self, example_path},
+    Day, Error,
+};
+
+pub fn handle(day: Day, overwrite: bool) -> Result<(), Error> {
+    let puzzle_path = get_puzzle_path(day);
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        return Err(Error::MissingPuzzle(day));
+    };
+
+    let examples = puzzle::examples(&puzzle::parse(&markdown));
+
+    if examples.inputs.is_empty() {
+        eprintln!("Could not find any examples in \"{puzzle_path}\".");
+        return Ok(());
+    }
+
+    for (i, example) in examples.inputs.iter().enumerate() {
+        let path = example_path(day, i + 1);
+
+        // scaffolding creates an empty example file, which is fine to replace.
+        let is_empty = fs::read_to_string(&path).map_or(true, |s| s.trim().is_empty());
+        if !is_empty && !overwrite {
+            println!("Skipped existing example file \"{path}\"");
+            continue;
+        }
+
+        fs::write(&path, example).map_err(Error::io("write", &path))?;
+        println!("Created example file \"{path}\"");
+    }
+
+    let module_path = format!("src/bin/{day}.rs");
+    let Ok(mut module) = fs::read_to_string(&module_path) else {
+        println!("---");
+        println!("🎄 Type `cargo scaffold {day}` to create a module with example tests.");
+        return Ok(());
+    };
+
+    for (part, (example, answer)) in (1..).zip(&examples.parts) {
+        let Some(answer) = answer else {
+            continue;
+        };
+
+        match puzzle::fill_test(&module, part, *example, answer) {
+            Some(filled) if filled != module => {
+                println!("Expecting {answer} for part {part} in \"{module_path}\"");
+                module = filled;
+            }
+            Some(_) => {}
+            None => println!(
+                "Expected answer of part {part} is {answer}, which does not fit its return type. Fill in the test in \"{module_path}\" manually."
+            ),
+        }
+    }
+
+    fs::write(&module_path, module).map_err(Error::io("write", &module_path))
+}
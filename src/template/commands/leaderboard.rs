@@ -0,0 +1,27 @@
+use std::{fs, path::Path};
+
+use crate::template::{
+    leaderboard::{self, Leaderboard, Scoring},
+    Day, Error,
+};
+
+pub fn handle(path: &Path, day: Option<Day>, scoring: Scoring) -> Result<(), Error> {
+    let leaderboard = fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(Leaderboard::try_from)
+        .map_err(|message| Error::Leaderboard {
+            path: path.into(),
+            message,
+        })?;
+
+    print!("{}", leaderboard::render_rankings(&leaderboard, scoring));
+
+    let days = day.map_or_else(|| leaderboard.days(), |day| vec![day]);
+
+    for day in days {
+        println!();
+        print!("{}", leaderboard::render_day(&leaderboard, day));
+    }
+
+    Ok(())
+}
@@ -1,6 +1,14 @@
+pub mod accept;
 pub mod all;
+pub mod completions;
 pub mod download;
+pub mod examples;
+pub mod leaderboard;
 pub mod read;
+pub mod report;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
@@ -0,0 +1,26 @@
+use std::{fs, path::PathBuf};
+
+use crate::template::{
+    answers::{current_year, Answers},
+    report::{self, Format},
+    timings::Timings,
+    Error,
+};
+
+pub fn handle(format: Format, out: Option<PathBuf>) -> Result<(), Error> {
+    let days = report::collect(
+        &Timings::read_from_file()?,
+        &Answers::read_from_file()?,
+        &current_year(),
+    );
+    let rendered = report::render(&days, format);
+
+    let Some(out) = out else {
+        print!("{rendered}");
+        return Ok(());
+    };
+
+    fs::write(&out, rendered).map_err(Error::io("write", &out))?;
+    println!("Wrote report to \"{}\".", out.display());
+    Ok(())
+}
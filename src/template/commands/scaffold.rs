@@ -1,13 +1,15 @@
 use std::{
     fs::{File, OpenOptions},
     io::Write,
-    process,
+    time::Duration,
 };
 
-use crate::template::Day;
-
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use crate::template::{
+    clock::{self, SystemClock},
+    commands::{download, examples},
+    scaffolds::{self, Context},
+    Day, Error, ANSI_ITALIC, ANSI_RESET,
+};
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,61 +21,84 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Create an empty data file. Returns `false` if it exists already, e.g. a downloaded input.
+fn create_data_file(path: &str) -> Result<bool, std::io::Error> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
 }
 
-pub fn handle(day: Day, overwrite: bool) {
+pub fn handle(day: Day, overwrite: bool, template: &str) -> Result<(), Error> {
     let input_path = format!("data/inputs/{day}.txt");
     let example_path = format!("data/examples/{day}.txt");
     let module_path = format!("src/bin/{day}.rs");
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
-    };
+    let source = scaffolds::load(template).map_err(Error::Template)?;
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
-    }
+    let file =
+        safe_create_file(&module_path, overwrite).map_err(Error::io("create", &module_path))?;
+    write_module(file, day, &source, &module_path)?;
+    println!("Created module file \"{}\"", &module_path);
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
+    if create_data_file(&input_path).map_err(Error::io("create", &input_path))? {
+        println!("Created empty input file \"{}\"", &input_path);
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
+    if create_data_file(&example_path).map_err(Error::io("create", &example_path))? {
+        println!("Created empty example file \"{}\"", &example_path);
     }
 
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
+    Ok(())
+}
+
+/// Scaffold a day, then download its puzzle and extract its examples.
+/// A failed download is retried after each of `retry_delays`.
+/// Returns whether the download succeeded. A failed download keeps the scaffolded files and
+/// is only reported, so it can be retried with `cargo download`.
+pub fn handle_with_download(
+    day: Day,
+    overwrite: bool,
+    template: &str,
+    retry_delays: &[Duration],
+) -> Result<bool, Error> {
+    handle(day, overwrite, template)?;
+
+    let downloaded = clock::retry(
+        &SystemClock,
+        retry_delays,
+        || download::handle(day),
+        |e, delay| {
+            eprintln!(
+                "{ANSI_ITALIC}Failed to download day {day}: {e}\n\
+                Trying again in {}s.{ANSI_RESET}",
+                delay.as_secs()
+            );
+        },
+    );
+
+    if let Err(e) = downloaded {
+        eprintln!(
+            "{ANSI_ITALIC}Warning: failed to download day {day}: {e}\n\
+            The day was scaffolded, run `cargo download {day}` to try again.{ANSI_RESET}"
+        );
+        return Ok(false);
+    }
+
+    // the module was just created, render it again to fill in the puzzle title.
+    let module_path = format!("src/bin/{day}.rs");
+    let source = scaffolds::load(template).map_err(Error::Template)?;
+    let file = safe_create_file(&module_path, true).map_err(Error::io("create", &module_path))?;
+    write_module(file, day, &source, &module_path)?;
+
+    examples::handle(day, false)?;
+    Ok(true)
+}
+
+fn write_module(mut file: File, day: Day, source: &str, path: &str) -> Result<(), Error> {
+    file.write_all(scaffolds::render(source, day, &Context::read(day)).as_bytes())
+        .map_err(Error::io("write", path))
 }
@@ -1,8 +1,26 @@
-use std::process::{Command, Stdio};
+use std::{
+    env, fs,
+    io::{stdout, Write},
+    path::{Path, PathBuf},
+    process::{self, Command, ExitStatus, Stdio},
+};
 
-use crate::template::Day;
+use crate::template::{
+    protocol::{self, PartRecord, REPORT_FILE_ENV},
+    run_multi::child_commands::timing_from_records,
+    runner::{InputSource, RunOptions},
+    timings::Timings,
+    watch::{self, Snapshot, CLEAR_SCREEN},
+    Day, Error, ANSI_ITALIC, ANSI_RESET,
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    options: &RunOptions,
+    watch: Option<Watch>,
+) -> Result<(), Error> {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if dhat {
@@ -17,18 +35,128 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     }
 
     cmd_args.push("--".to_string());
+    cmd_args.extend(options.to_args());
 
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+    if let Some(watch) = watch {
+        return watch_solution(day, &cmd_args, &options.input, watch);
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+    // heap usage is only worth keeping when measured against the puzzle input.
+    let report_path = (dhat && options.input == InputSource::Puzzle)
+        .then(|| env::temp_dir().join(format!("aoc-dhat-{}-{day}.jsonl", process::id())));
+
+    let status = run_cargo(&cmd_args, report_path.as_deref())?;
+
+    if let Some(report_path) = report_path {
+        if let Ok(records) = protocol::read_records(&report_path) {
+            store_heap(day, &records);
+        }
+        let _ = fs::remove_file(&report_path);
+    }
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::SolutionFailed {
+            day,
+            code: status.code(),
+        })
+    }
+}
+
+/// Store the heap usage of the solved parts in the timings file.
+fn store_heap(day: Day, records: &[PartRecord]) {
+    let timing = timing_from_records(records, day);
+    if timing.part_1_heap.is_none() && timing.part_2_heap.is_none() {
+        return;
+    }
+
+    let stored = match Timings::read_from_file() {
+        Ok(stored) => stored,
+        Err(e) => {
+            eprintln!("Failed to store heap usage: {e}");
+            return;
+        }
+    };
+    let timings = stored.merge_heap(&Timings { data: vec![timing] });
+
+    match timings.store_file() {
+        Ok(()) => println!("\n{ANSI_ITALIC}Stored heap usage of day {day}.{ANSI_RESET}"),
+        Err(e) => eprintln!("Failed to store heap usage: {e}"),
+    }
+}
+
+/// Settings of `cargo solve <day> --watch`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Watch {
+    /// Run the example tests of the day after each run.
+    pub test: bool,
+}
+
+/// Rerun the solution whenever one of its files changes. Runs until interrupted.
+fn watch_solution(
+    day: Day,
+    cmd_args: &[String],
+    input: &InputSource,
+    watch: Watch,
+) -> Result<(), Error> {
+    // stdin is consumed by the first run, reruns would not see an input.
+    if *input == InputSource::Stdin {
+        return Err(Error::Config(
+            "--watch cannot be used with --input -, as stdin can only be read once.".into(),
+        ));
+    }
+
+    let report_path = env::temp_dir().join(format!("aoc-watch-{}-{day}.jsonl", process::id()));
+    let mut snapshot = Snapshot::take(day, input);
+    let mut previous: Option<Vec<PartRecord>> = None;
+    let mut changes: Vec<PathBuf> = vec![];
+
+    loop {
+        print!("{CLEAR_SCREEN}");
+        let _ = stdout().flush();
+
+        if let Some(path) = changes.first() {
+            println!("{ANSI_ITALIC}Changed: {}{ANSI_RESET}\n", path.display());
+        }
+
+        let _ = fs::remove_file(&report_path);
+        run_cargo(cmd_args, Some(&report_path))?;
+
+        if watch.test {
+            println!();
+            run_cargo(&["test".into(), "--bin".into(), day.to_string()], None)?;
+        }
+
+        // records are missing if the build failed or the solution panicked.
+        match protocol::read_records(&report_path) {
+            Ok(records) if !records.is_empty() => {
+                if let Some(previous) = &previous {
+                    println!();
+                    watch::print_changes(previous, &records);
+                }
+                previous = Some(records);
+            }
+            _ => {}
+        }
+
+        println!(
+            "\n{ANSI_ITALIC}Watching day {day} for changes. Press Ctrl+C to stop.{ANSI_RESET}"
+        );
+
+        (snapshot, changes) = watch::wait_for_change(day, input, &snapshot);
+    }
+}
+
+fn run_cargo(args: &[String], report_path: Option<&Path>) -> Result<ExitStatus, Error> {
+    let mut cmd = Command::new("cargo");
+    cmd.args(args)
         .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .stderr(Stdio::inherit());
+
+    if let Some(report_path) = report_path {
+        cmd.env(REPORT_FILE_ENV, report_path);
+    }
 
-    cmd.wait().unwrap();
+    cmd.status().map_err(Error::io("run", "cargo"))
 }
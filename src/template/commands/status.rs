@@ -0,0 +1,45 @@
+use std::process::{Command, Stdio};
+
+use crate::template::{
+    all_days,
+    answers::current_year,
+    status::{self, ProgressStatus, TestState},
+    submissions::Submissions,
+    timings::Timings,
+    Day, Error,
+};
+
+/// Run the example tests of a day, discarding their output.
+fn run_tests(day: Day) -> TestState {
+    let status = Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &day.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+
+    match status {
+        Ok(status) if status.success() => TestState::Passed,
+        _ => TestState::Failed,
+    }
+}
+
+pub fn handle(test: bool) -> Result<(), Error> {
+    let timings = Timings::read_from_file()?;
+    let submissions = Submissions::read_from_file()?;
+    let year = current_year();
+
+    let mut days: Vec<ProgressStatus> = all_days()
+        .map(|day| ProgressStatus::read(day, &timings, &submissions, &year))
+        .collect();
+
+    if test {
+        for status in days.iter_mut().filter(|d| d.scaffolded) {
+            eprint!("\rRunning tests of day {}...", status.day);
+            status.tests = run_tests(status.day);
+        }
+        eprint!("\r\x1b[2K");
+    }
+
+    print!("{}", status::render(&days));
+    Ok(())
+}
@@ -1,34 +1,102 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::compare;
+use crate::template::history::{self, RunContext, TimingHistory};
+use crate::template::registry::Solution;
+use crate::template::run_multi::{run_multi, Mode, Timeouts};
+use crate::template::runner::{BenchConfig, RunOptions};
+use crate::template::timings::{Timings, TIMINGS_FILE_PATH};
+use crate::template::{all_days, readme_benchmarks, Day, Error};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    solutions: &[Solution],
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    isolated: bool,
+    bench: BenchConfig,
+    compare_threshold: Option<f64>,
+    part: Option<u8>,
+    timeout: Option<Duration>,
+    show_history: bool,
+    trend: bool,
+) -> Result<(), Error> {
+    if show_history {
+        let history = TimingHistory::read_from_file()?;
+        let days = day.map_or_else(|| history.days(), |day| vec![day]);
+        history::print_history(&history, &days);
+        return Ok(());
+    }
+
+    let timeouts =
+        Timeouts::from_env(timeout).map_err(|e| Error::Config(format!("invalid timeout: {e}")))?;
+
+    let stored_timings = Timings::read_from_file()?;
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
                 all_days().collect()
+            } else if compare_threshold.is_some() {
+                // when comparing, run every day that has stored timings to compare against.
+                stored_timings.data.iter().map(|t| t.day).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
                 all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                    .filter(|day| match part {
+                        Some(part) => !stored_timings.is_part_complete(*day, part),
+                        None => !stored_timings.is_day_complete(*day),
+                    })
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    // read the history before running, a file that cannot be read must not be overwritten.
+    let mut history = if store {
+        TimingHistory::read_from_file()?
+    } else {
+        TimingHistory::default()
+    };
+
+    let mode = Mode::select(solutions, isolated, true, timeouts);
+    let options = RunOptions {
+        timed: true,
+        bench,
+        part,
+        ..RunOptions::default()
+    };
+    let timings = run_multi(&days_to_run, &mode, &options).timings();
+
+    let regressions = compare_threshold.map(|threshold| {
+        let mut deltas = compare::compare(&stored_timings, &timings, threshold);
+        // only the selected part was benched, the other one is not missing.
+        if let Some(part) = part {
+            deltas.retain(|d| d.part == part);
+        }
+        println!();
+        compare::print_table(&deltas, threshold);
+        deltas.iter().filter(|d| d.is_failure()).count()
+    });
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        history.record(&timings, &RunContext::current(build_profile(&mode)));
+        history
+            .store_file()
+            .map_err(Error::io("write", history::HISTORY_FILE_PATH))?;
+
+        let merged_timings = match part {
+            Some(part) => stored_timings.merge_part(&timings, part),
+            None => stored_timings.merge(&timings),
+        };
+        merged_timings
+            .store_file()
+            .map_err(Error::io("write", TIMINGS_FILE_PATH))?;
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(merged_timings, trend.then_some(&history)) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +105,24 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    match regressions {
+        Some(regressions @ 1..) => Err(Error::Regressions(regressions)),
+        _ => Ok(()),
+    }
+}
+
+/// Name of the profile the solutions were built with.
+fn build_profile(mode: &Mode) -> &'static str {
+    match mode {
+        // in-process solutions are part of the current binary.
+        Mode::InProcess(_) if cfg!(debug_assertions) => "debug",
+        Mode::Isolated {
+            is_release: false, ..
+        }
+        | Mode::Parallel {
+            is_release: false, ..
+        } => "debug",
+        _ => "release",
+    }
 }
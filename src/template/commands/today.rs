@@ -0,0 +1,48 @@
+use std::io::{stdout, Write};
+
+use crate::template::{
+    aoc_cli,
+    clock::{self, format_seconds, Clock, SystemClock},
+    commands::{read, scaffold},
+    scaffolds, Day, Error,
+};
+
+pub fn handle(wait: bool) -> Result<(), Error> {
+    let day = if wait {
+        wait_for_unlock(&SystemClock)?
+    } else {
+        Day::today().ok_or(Error::NotAdvent)?
+    };
+
+    // right at the unlock, the puzzle may not be available yet.
+    let retry_delays: &[_] = if wait { &clock::RETRY_DELAYS } else { &[] };
+
+    if scaffold::handle_with_download(day, false, scaffolds::DEFAULT_TEMPLATE, retry_delays)? {
+        read::handle(day)?;
+    }
+    Ok(())
+}
+
+/// Show a countdown until the next puzzle unlocks, then return its day.
+fn wait_for_unlock(clock: &impl Clock) -> Result<Day, Error> {
+    let unlock = clock::next_unlock(clock.now());
+    let label = format!("Day {} of {}", unlock.day.into_inner(), unlock.year);
+
+    // downloads use `AOC_YEAR`, which would fetch the puzzle of another year.
+    if let Some(year) = aoc_cli::get_year() {
+        if i64::from(year) != unlock.year {
+            return Err(Error::Config(format!(
+                "the next puzzle is {label}, but AOC_YEAR is set to {year}. \
+                Please update AOC_YEAR in \".cargo/config.toml\"."
+            )));
+        }
+    }
+
+    clock::wait_for(clock, &unlock, |remaining| {
+        print!("\r⏳ {label} unlocks in {} ", format_seconds(remaining));
+        stdout().flush().unwrap();
+    });
+
+    println!("\r\x1b[2K🎄 {label} unlocked!");
+    Ok(unlock.day)
+}
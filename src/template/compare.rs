@@ -0,0 +1,204 @@
+/// Compares fresh benchmark results against stored timings to detect regressions.
+use crate::template::{
+    stats::format_nanos,
+    timings::{Timing, Timings},
+    Day, ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET,
+};
+
+/// Default relative threshold in percent above which a slowdown counts as a regression.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// How a part's current timing relates to its stored timing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Regression,
+    Improvement,
+    Unchanged,
+    /// The part has a stored timing, but did not produce one now.
+    Missing,
+    /// The part has no stored timing to compare against.
+    New,
+}
+
+/// Comparison of a single part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartDelta {
+    pub day: Day,
+    pub part: u8,
+    pub stored_nanos: Option<f64>,
+    pub current_nanos: Option<f64>,
+    pub verdict: Verdict,
+}
+
+impl PartDelta {
+    /// Relative change in percent, if both timings are present.
+    pub fn delta_percent(&self) -> Option<f64> {
+        match (self.stored_nanos, self.current_nanos) {
+            (Some(stored), Some(current)) if stored > 0.0 => {
+                Some((current - stored) / stored * 100.0)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self.verdict, Verdict::Regression | Verdict::Missing)
+    }
+}
+
+/// Compare every part of `current` against `stored` with a relative `threshold` in percent.
+pub fn compare(stored: &Timings, current: &Timings, threshold: f64) -> Vec<PartDelta> {
+    current
+        .data
+        .iter()
+        .flat_map(|timing| {
+            let stored = stored.get(timing.day);
+            [1, 2].map(|part| compare_part(stored, timing, part, threshold))
+        })
+        .flatten()
+        .collect()
+}
+
+fn compare_part(
+    stored: Option<&Timing>,
+    current: &Timing,
+    part: u8,
+    threshold: f64,
+) -> Option<PartDelta> {
+    let stored_nanos = stored.and_then(|t| t.part_nanos(part));
+    let current_nanos = current.part_nanos(part);
+
+    let verdict = match (stored_nanos, current_nanos) {
+        (None, None) => return None,
+        (None, Some(_)) => Verdict::New,
+        (Some(_), None) => Verdict::Missing,
+        (Some(stored), Some(current)) => {
+            let ratio = (current - stored) / stored * 100.0;
+            if ratio > threshold {
+                Verdict::Regression
+            } else if ratio < -threshold {
+                Verdict::Improvement
+            } else {
+                Verdict::Unchanged
+            }
+        }
+    };
+
+    Some(PartDelta {
+        day: current.day,
+        part,
+        stored_nanos,
+        current_nanos,
+        verdict,
+    })
+}
+
+/// Print a per-part table of deltas to the terminal.
+pub fn print_table(deltas: &[PartDelta], threshold: f64) {
+    println!(
+        "{ANSI_BOLD}Comparison{ANSI_RESET} {ANSI_ITALIC}(threshold: ±{threshold}%){ANSI_RESET}"
+    );
+    println!(
+        "{:<6}  {:<4}  {:>10}  {:>10}  {:>8}  Verdict",
+        "Day", "Part", "Stored", "Current", "Delta"
+    );
+
+    for delta in deltas {
+        let format = |nanos: Option<f64>| nanos.map_or_else(|| "-".into(), format_nanos);
+        let percent = delta
+            .delta_percent()
+            .map_or_else(|| "-".into(), |p| format!("{p:+.1}%"));
+
+        let (color, label) = match delta.verdict {
+            Verdict::Regression => (ANSI_RED, "regression"),
+            Verdict::Missing => (ANSI_RED, "missing"),
+            Verdict::Improvement => (ANSI_GREEN, "improvement"),
+            Verdict::Unchanged => ("", "unchanged"),
+            Verdict::New => ("", "new"),
+        };
+
+        println!(
+            "{color}{:<6}  {:<4}  {:>10}  {:>10}  {:>8}  {label}{ANSI_RESET}",
+            format!("Day {}", delta.day),
+            delta.part,
+            format(delta.stored_nanos),
+            format(delta.current_nanos),
+            percent,
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, Verdict};
+    use crate::{
+        day,
+        template::{
+            stats::BenchStats,
+            timings::{Timing, Timings},
+            Day,
+        },
+    };
+
+    fn timing(day: u8, part_1_nanos: Option<f64>, part_2_nanos: Option<f64>) -> Timing {
+        Timing {
+            part_1_stats: part_1_nanos.map(BenchStats::from_mean),
+            part_2_stats: part_2_nanos.map(BenchStats::from_mean),
+            ..Timing::empty(Day::new(day).unwrap())
+        }
+    }
+
+    #[test]
+    fn classifies_parts() {
+        let stored = Timings {
+            data: vec![timing(1, Some(100_000.0), Some(10_000_000.0))],
+        };
+        let current = Timings {
+            data: vec![timing(1, Some(120_000.0), Some(8_000_000.0))],
+        };
+
+        let deltas = compare(&stored, &current, 10.0);
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].verdict, Verdict::Regression);
+        assert_eq!(deltas[0].delta_percent().unwrap().round(), 20.0);
+        assert_eq!(deltas[1].verdict, Verdict::Improvement);
+    }
+
+    #[test]
+    fn respects_threshold() {
+        let stored = Timings {
+            data: vec![timing(1, Some(100_000.0), None)],
+        };
+        let current = Timings {
+            data: vec![timing(1, Some(109_000.0), None)],
+        };
+
+        let deltas = compare(&stored, &current, 10.0);
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].verdict, Verdict::Unchanged);
+        assert!(!deltas[0].is_failure());
+    }
+
+    #[test]
+    fn handles_new_and_missing_parts() {
+        let stored = Timings {
+            data: vec![timing(1, Some(1_000_000.0), None)],
+        };
+        let current = Timings {
+            data: vec![
+                timing(1, None, Some(1_000_000.0)),
+                timing(2, Some(1_000_000.0), None),
+            ],
+        };
+
+        let deltas = compare(&stored, &current, 10.0);
+        assert_eq!(deltas.len(), 3);
+        assert_eq!(deltas[0].verdict, Verdict::Missing);
+        assert!(deltas[0].is_failure());
+        assert_eq!(deltas[1].verdict, Verdict::New);
+        assert_eq!(deltas[2].day, day!(2));
+        assert_eq!(deltas[2].verdict, Verdict::New);
+    }
+}
@@ -0,0 +1,186 @@
+/// Errors returned by the functions in [`commands`](crate::template::commands).
+///
+/// Commands do not exit the process themselves, so they can be driven from other tools and tests.
+/// The `advent_of_code` binary prints these errors and maps them to an exit code.
+use std::{fmt::Display, io, path::PathBuf};
+
+use crate::template::{aoc_cli::AocCommandError, input::BAD_INPUT_EXIT_CODE, Day};
+
+#[derive(Debug)]
+pub enum Error {
+    /// aoc-cli is not installed or failed.
+    AocCli(AocCommandError),
+    /// A file could not be read or written.
+    Io {
+        /// What was done with the file, e.g. `create`.
+        action: &'static str,
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// The puzzle description of a day has not been downloaded yet.
+    MissingPuzzle(Day),
+    /// A scaffold template does not exist.
+    Template(String),
+    /// An option or environment variable has an invalid value.
+    Config(String),
+    /// A leaderboard export could not be parsed.
+    Leaderboard { path: PathBuf, message: String },
+    /// A data file, e.g. the stored timings, exists but could not be parsed.
+    DataFile { path: PathBuf, message: String },
+    /// `today` was run outside of advent.
+    NotAdvent,
+    /// The input of a solution or its parameters are missing or damaged.
+    BadInput(String),
+    /// Answers that differ from the accepted answers in `--verify`.
+    WrongAnswers(usize),
+    /// The solution of a day exited with a non-zero status.
+    SolutionFailed { day: Day, code: Option<i32> },
+    /// Days that failed to run in `cargo all`.
+    DaysFailed(usize),
+    /// Parts that regressed beyond the threshold in `cargo time --compare`.
+    Regressions(usize),
+}
+
+impl Error {
+    /// Helper for mapping IO errors of a file, e.g. `.map_err(Error::io("write", path))`.
+    pub fn io(action: &'static str, path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Self {
+        let path = path.into();
+        move |source| Error::Io {
+            action,
+            path,
+            source,
+        }
+    }
+
+    /// Exit code of the process. A failing solution passes on its own exit code.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::SolutionFailed {
+                code: Some(code), ..
+            } => *code,
+            Error::BadInput(_) => BAD_INPUT_EXIT_CODE,
+            _ => 1,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::AocCli(e) => write!(f, "{e}"),
+            Error::Io {
+                action,
+                path,
+                source,
+            } => write!(f, "failed to {action} \"{}\": {source}", path.display()),
+            Error::MissingPuzzle(day) => write!(
+                f,
+                "the puzzle of day {day} has not been downloaded. Try running `cargo download {day}` first."
+            ),
+            Error::Template(message) | Error::Config(message) | Error::BadInput(message) => {
+                f.write_str(message)
+            }
+            Error::Leaderboard { path, message } => {
+                write!(f, "failed to read leaderboard \"{}\": {message}", path.display())
+            }
+            Error::DataFile { path, message } => write!(
+                f,
+                "failed to parse \"{}\": {message} Fix or remove the file and try again.",
+                path.display()
+            ),
+            Error::NotAdvent => f.write_str(
+                "`today` command can only be run between the 1st and the 25th of december. \
+                Please use `scaffold` with a specific day, or append `--wait` to wait for the next puzzle.",
+            ),
+            Error::SolutionFailed { day, code } => match code {
+                Some(code) => write!(f, "the solution of day {day} exited with status {code}."),
+                None => write!(f, "the solution of day {day} was terminated."),
+            },
+            Error::WrongAnswers(count) => {
+                write!(f, "{count} answer(s) differ from the accepted answers.")
+            }
+            Error::DaysFailed(count) => write!(f, "{count} day(s) failed."),
+            Error::Regressions(count) => {
+                write!(f, "{count} part(s) regressed beyond the threshold.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::AocCli(e) => Some(e),
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<AocCommandError> for Error {
+    fn from(value: AocCommandError) -> Self {
+        Error::AocCli(value)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Error;
+    use crate::{
+        day,
+        template::{aoc_cli::AocCommandError, input::BAD_INPUT_EXIT_CODE},
+    };
+    use std::io;
+
+    #[test]
+    fn maps_exit_codes() {
+        let failed = |code| Error::SolutionFailed { day: day!(5), code };
+
+        assert_eq!(failed(Some(101)).exit_code(), 101);
+        assert_eq!(failed(None).exit_code(), 1);
+        assert_eq!(Error::DaysFailed(2).exit_code(), 1);
+        assert_eq!(
+            Error::BadInput("\"data/inputs/05.txt\" is empty.".into()).exit_code(),
+            BAD_INPUT_EXIT_CODE
+        );
+    }
+
+    #[test]
+    fn describes_errors() {
+        let err = Error::io("read", "data/inputs/05.txt")(io::Error::from(io::ErrorKind::NotFound));
+        assert!(err
+            .to_string()
+            .starts_with("failed to read \"data/inputs/05.txt\": "));
+
+        let err = Error::from(AocCommandError::CommandNotFound(io::Error::from(
+            io::ErrorKind::NotFound,
+        )));
+        assert!(err.to_string().contains("cargo install aoc-cli"));
+
+        // the error of starting aoc-cli is kept as the source.
+        let err = Error::from(AocCommandError::CommandNotCallable(io::Error::from(
+            io::ErrorKind::PermissionDenied,
+        )));
+        let source = std::error::Error::source(&err).and_then(std::error::Error::source);
+        assert_eq!(
+            source.map(ToString::to_string),
+            Some(io::Error::from(io::ErrorKind::PermissionDenied).to_string())
+        );
+
+        assert_eq!(
+            Error::MissingPuzzle(day!(5)).to_string(),
+            "the puzzle of day 05 has not been downloaded. Try running `cargo download 05` first."
+        );
+
+        let err = Error::DataFile {
+            path: "data/timing_history.json".into(),
+            message: "not valid JSON file.".into(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "failed to parse \"data/timing_history.json\": not valid JSON file. Fix or remove the file and try again."
+        );
+    }
+}
@@ -0,0 +1,540 @@
+/// Append-only history of benchmark results, used to follow how the performance of a day evolves.
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, ErrorKind},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    clock, stats::format_nanos, timings::Timings, Day, Error, ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC,
+    ANSI_RED, ANSI_RESET,
+};
+
+pub static HISTORY_FILE_PATH: &str = "./data/timing_history.json";
+
+/// Name of the environment variable that overrides the machine name stored with each run.
+pub const MACHINE_ENV: &str = "AOC_MACHINE";
+
+/// Placeholder for a commit or machine that could not be determined.
+static UNKNOWN: &str = "unknown";
+
+/// Number of runs shown in a sparkline.
+const SPARKLINE_LENGTH: usize = 10;
+
+/// Where and on which revision a benchmark ran.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunContext {
+    /// Short hash of the checked out commit, suffixed with `-dirty` if `src` has uncommitted changes.
+    pub commit: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub machine: String,
+    /// Build profile the solutions ran with, e.g. `release`.
+    pub profile: String,
+}
+
+impl RunContext {
+    /// Describe a run that happens now, in the current working tree and on the current machine.
+    pub fn current(profile: &str) -> Self {
+        RunContext {
+            commit: current_commit().unwrap_or_else(|| UNKNOWN.into()),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            machine: current_machine().unwrap_or_else(|| UNKNOWN.into()),
+            profile: profile.into(),
+        }
+    }
+
+    /// Whether timings of both runs can be compared, i.e. they ran on the same machine and profile.
+    pub fn same_setup(&self, other: &RunContext) -> bool {
+        self.machine == other.machine && self.profile == other.profile
+    }
+}
+
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let is_dirty = Command::new("git")
+        .args(["diff", "--quiet", "HEAD", "--", "src"])
+        .status()
+        .is_ok_and(|status| status.code() == Some(1));
+
+    Some(if is_dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+fn current_machine() -> Option<String> {
+    if let Ok(machine) = env::var(MACHINE_ENV) {
+        return Some(machine);
+    }
+
+    let output = Command::new("hostname").output().ok()?;
+    let machine = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !machine.is_empty()).then_some(machine)
+}
+
+/// Benchmark result of a single day in one run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub context: RunContext,
+    pub day: Day,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
+}
+
+impl HistoryEntry {
+    /// Mean duration of a part in nanoseconds.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        match part {
+            1 => self.part_1_nanos,
+            2 => self.part_2_nanos,
+            _ => None,
+        }
+    }
+
+    /// Sum of the mean durations of all parts that were benched.
+    pub fn total_nanos(&self) -> f64 {
+        [1, 2].iter().filter_map(|p| self.part_nanos(*p)).sum()
+    }
+}
+
+/// Append-only history of benchmark results.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TimingHistory {
+    pub data: Vec<HistoryEntry>,
+}
+
+impl TimingHistory {
+    /// Dehydrate the history to a JSON file.
+    pub fn store_file(&self) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(HISTORY_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the history from a JSON file. If not present, returns an empty history.
+    /// A file that cannot be read or parsed is an error, so it is never overwritten by `--store`.
+    pub fn read_from_file() -> Result<Self, Error> {
+        match fs::read_to_string(HISTORY_FILE_PATH) {
+            Ok(content) => TimingHistory::try_from(content).map_err(|message| Error::DataFile {
+                path: HISTORY_FILE_PATH.into(),
+                message,
+            }),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(TimingHistory::default()),
+            Err(e) => Err(Error::io("read", HISTORY_FILE_PATH)(e)),
+        }
+    }
+
+    /// Append the benched parts of every day in `timings`.
+    pub fn record(&mut self, timings: &Timings, context: &RunContext) {
+        self.data.extend(
+            timings
+                .data
+                .iter()
+                .filter(|t| t.part_nanos(1).is_some() || t.part_nanos(2).is_some())
+                .map(|t| HistoryEntry {
+                    context: context.clone(),
+                    day: t.day,
+                    part_1_nanos: t.part_nanos(1),
+                    part_2_nanos: t.part_nanos(2),
+                }),
+        );
+    }
+
+    /// Days with at least one entry, in calendar order.
+    pub fn days(&self) -> Vec<Day> {
+        let mut days: Vec<Day> = self.data.iter().map(|e| e.day).collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    /// Entries of a day, oldest first.
+    pub fn for_day(&self, day: Day) -> Vec<&HistoryEntry> {
+        let mut entries: Vec<&HistoryEntry> = self.data.iter().filter(|e| e.day == day).collect();
+        entries.sort_by_key(|e| e.context.timestamp);
+        entries
+    }
+
+    /// Entries of a day that ran on the same machine and profile as its latest entry, oldest first.
+    /// Timings from different setups are not comparable, so trends are only computed over these.
+    pub fn trend(&self, day: Day) -> Vec<&HistoryEntry> {
+        let entries = self.for_day(day);
+        let Some(latest) = entries.last() else {
+            return vec![];
+        };
+        let latest = latest.context.clone();
+
+        entries
+            .into_iter()
+            .filter(|e| e.context.same_setup(&latest))
+            .collect()
+    }
+}
+
+/// Render values as a line of block characters scaled between their minimum and maximum.
+pub fn sparkline(values: &[f64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    values
+        .iter()
+        .map(|x| {
+            if max <= min {
+                return BARS[0];
+            }
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let index = ((x - min) / (max - min) * (BARS.len() - 1) as f64).round() as usize;
+            BARS[index]
+        })
+        .collect()
+}
+
+/// Sparkline over the total duration of the latest runs of a day, if it ran at least twice.
+pub fn day_sparkline(history: &TimingHistory, day: Day) -> Option<String> {
+    let trend = history.trend(day);
+    let totals: Vec<f64> = trend
+        .iter()
+        .skip(trend.len().saturating_sub(SPARKLINE_LENGTH))
+        .map(|e| e.total_nanos())
+        .collect();
+
+    (totals.len() > 1).then(|| sparkline(&totals))
+}
+
+/// Format seconds since the unix epoch as an ISO 8601 date in UTC.
+pub fn format_date(timestamp: u64) -> String {
+    #[allow(clippy::cast_possible_wrap)]
+    let (year, month, day) = clock::civil_from_days((timestamp / 86_400) as i64);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Print every entry of the given days, followed by the trend of each part.
+pub fn print_history(history: &TimingHistory, days: &[Day]) {
+    if days.is_empty() {
+        println!("No timing history recorded yet. Run `cargo time --store` to start one.");
+        return;
+    }
+
+    for (i, day) in days.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let entries = history.for_day(*day);
+        if entries.is_empty() {
+            println!("No history.");
+            continue;
+        }
+
+        println!(
+            "{:<10}  {:<14}  {:<16}  {:<8}  {:>10}  {:>10}",
+            "Date", "Commit", "Machine", "Profile", "Part 1", "Part 2"
+        );
+
+        let format = |nanos: Option<f64>| nanos.map_or_else(|| "-".into(), format_nanos);
+        for entry in &entries {
+            println!(
+                "{:<10}  {:<14}  {:<16}  {:<8}  {:>10}  {:>10}",
+                format_date(entry.context.timestamp),
+                entry.context.commit,
+                entry.context.machine,
+                entry.context.profile,
+                format(entry.part_1_nanos),
+                format(entry.part_2_nanos),
+            );
+        }
+
+        let trend = history.trend(*day);
+        let latest = &trend[trend.len() - 1].context;
+        println!(
+            "{ANSI_ITALIC}Trend on {} ({}):{ANSI_RESET}",
+            latest.machine, latest.profile
+        );
+
+        for part in [1, 2] {
+            let nanos: Vec<f64> = trend.iter().filter_map(|e| e.part_nanos(part)).collect();
+            if let Some(line) = format_trend(&nanos) {
+                println!("Part {part}: {line}");
+            }
+        }
+    }
+}
+
+/// Sparkline of the latest durations, followed by the change from the first to the last one.
+fn format_trend(nanos: &[f64]) -> Option<String> {
+    let (first, last) = (nanos.first()?, nanos.last()?);
+    let recent = &nanos[nanos.len().saturating_sub(SPARKLINE_LENGTH)..];
+
+    if nanos.len() == 1 {
+        return Some(format!("{} (single run)", format_nanos(*last)));
+    }
+
+    let percent = (last - first) / first * 100.0;
+    let color = if percent > 0.0 { ANSI_RED } else { ANSI_GREEN };
+
+    Some(format!(
+        "{} {} → {} {color}({percent:+.1}%){ANSI_RESET}",
+        sparkline(recent),
+        format_nanos(*first),
+        format_nanos(*last),
+    ))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<TimingHistory> for JsonValue {
+    fn from(value: TimingHistory) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for TimingHistory {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(TimingHistory {
+            data: json_data
+                .iter()
+                .map(HistoryEntry::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "commit".into(),
+            JsonValue::String(value.context.commit.clone()),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.context.timestamp as f64),
+        );
+        map.insert(
+            "machine".into(),
+            JsonValue::String(value.context.machine.clone()),
+        );
+        map.insert(
+            "profile".into(),
+            JsonValue::String(value.context.profile.clone()),
+        );
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, nanos) in [
+            ("part_1_nanos", value.part_1_nanos),
+            ("part_2_nanos", value.part_2_nanos),
+        ] {
+            map.insert(key.into(), nanos.map_or(JsonValue::Null, JsonValue::Number));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("Expected history entry.{key} to be a string."))
+        };
+
+        let nanos = |key: &str| {
+            json.get(key)
+                .map(|v| {
+                    if v.is_null() {
+                        None
+                    } else {
+                        v.get::<f64>().copied()
+                    }
+                })
+                .ok_or(format!(
+                    "Expected history entry.{key} to be null or a number."
+                ))
+        };
+
+        let day =
+            Day::from_str(string("day")?).or(Err("Expected history entry.day to be a Day."))?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected history entry.timestamp to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(HistoryEntry {
+            context: RunContext {
+                commit: string("commit")?.clone(),
+                timestamp: timestamp as u64,
+                machine: string("machine")?.clone(),
+                profile: string("profile")?.clone(),
+            },
+            day,
+            part_1_nanos: nanos("part_1_nanos")?,
+            part_2_nanos: nanos("part_2_nanos")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_date, sparkline, HistoryEntry, RunContext, TimingHistory};
+    use crate::{
+        day,
+        template::{
+            stats::BenchStats,
+            timings::{Timing, Timings},
+            Day,
+        },
+    };
+    use tinyjson::JsonValue;
+
+    fn context(commit: &str, timestamp: u64, machine: &str) -> RunContext {
+        RunContext {
+            commit: commit.into(),
+            timestamp,
+            machine: machine.into(),
+            profile: "release".into(),
+        }
+    }
+
+    fn entry(day: Day, context: RunContext, part_1_nanos: f64) -> HistoryEntry {
+        HistoryEntry {
+            context,
+            day,
+            part_1_nanos: Some(part_1_nanos),
+            part_2_nanos: None,
+        }
+    }
+
+    #[test]
+    fn records_benched_days() {
+        let mut benched = Timing::empty(day!(1));
+        benched.set_part(1, &BenchStats::from_mean(10_000_000.0));
+        let timings = Timings {
+            data: vec![benched, Timing::empty(day!(2))],
+        };
+
+        let mut history = TimingHistory::default();
+        history.record(&timings, &context("abc1234", 0, "box"));
+        history.record(&timings, &context("def5678", 10, "box"));
+
+        assert_eq!(history.data.len(), 2);
+        assert_eq!(history.days(), vec![day!(1)]);
+        assert_eq!(history.data[1].context.commit, "def5678");
+        assert_eq!(history.data[1].part_1_nanos, Some(10_000_000.0));
+        assert_eq!(history.data[1].part_2_nanos, None);
+    }
+
+    #[test]
+    fn computes_trends_per_setup() {
+        let history = TimingHistory {
+            data: vec![
+                entry(day!(1), context("c", 30, "laptop"), 3.0),
+                entry(day!(1), context("a", 10, "laptop"), 1.0),
+                entry(day!(1), context("b", 20, "desktop"), 2.0),
+                entry(day!(2), context("a", 10, "laptop"), 5.0),
+            ],
+        };
+
+        let trend: Vec<f64> = history
+            .trend(day!(1))
+            .iter()
+            .filter_map(|e| e.part_1_nanos)
+            .collect();
+        assert_eq!(trend, vec![1.0, 3.0]);
+        assert_eq!(history.for_day(day!(1)).len(), 3);
+        assert!(history.trend(day!(3)).is_empty());
+    }
+
+    #[test]
+    fn renders_sparklines() {
+        assert_eq!(sparkline(&[1.0, 8.0, 4.5]), "▁█▅");
+        assert_eq!(sparkline(&[2.0, 2.0]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(1_733_011_200), "2024-12-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+    }
+
+    #[test]
+    fn roundtrips_history() {
+        let history = TimingHistory {
+            data: vec![
+                entry(
+                    day!(1),
+                    context("abc1234-dirty", 1_733_011_200, "box"),
+                    74.0,
+                ),
+                HistoryEntry {
+                    context: context("abc1234", 1_733_011_300, "box"),
+                    day: day!(17),
+                    part_1_nanos: None,
+                    part_2_nanos: Some(1_500.0),
+                },
+            ],
+        };
+        let json = JsonValue::from(history.clone()).stringify().unwrap();
+        assert_eq!(TimingHistory::try_from(json).unwrap(), history);
+    }
+}
@@ -0,0 +1,179 @@
+/// Sanity checks for input files, run before a solution sees its input.
+///
+/// An empty, truncated or otherwise damaged input makes most solutions fail with confusing panics,
+/// e.g. an overflow in `blocks.len() - 1`. These checks report what is wrong with the file instead.
+use std::{fmt::Display, fs, io::ErrorKind, path::Path};
+
+use crate::template::{Day, ANSI_ITALIC, ANSI_RESET};
+
+/// Something that is wrong with an input file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Problem {
+    Missing,
+    /// The file is empty or only contains whitespace, e.g. right after `cargo scaffold`.
+    Empty,
+    /// The file starts with a byte order mark, which some editors add when saving.
+    ByteOrderMark,
+    /// Lines end with `\r\n`, e.g. after a checkout with `core.autocrlf`.
+    CarriageReturns,
+    /// The file does not end with a newline, which usually means it was cut off while copying.
+    MissingFinalNewline,
+    /// The file ends with blank lines, e.g. after pasting it into an editor.
+    TrailingBlankLines,
+}
+
+impl Problem {
+    /// Whether the problem stops the solution from running. Other problems are only warned
+    /// about, as the input may still be intact.
+    pub fn is_fatal(self) -> bool {
+        !matches!(
+            self,
+            Problem::MissingFinalNewline | Problem::TrailingBlankLines
+        )
+    }
+
+    /// Whether the problem stops a solution from reading a file of `folder`. An empty example
+    /// is only warned about, since scaffolding creates one and its tests expect `None` until the
+    /// examples are filled in.
+    pub fn is_fatal_for(self, folder: &str) -> bool {
+        self.is_fatal() && !(self == Problem::Empty && folder == "examples")
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Problem::Missing => "does not exist",
+            Problem::Empty => "is empty",
+            Problem::ByteOrderMark => "starts with a byte order mark",
+            Problem::CarriageReturns => "has Windows (CRLF) line endings",
+            Problem::MissingFinalNewline => "does not end with a newline and may be truncated",
+            Problem::TrailingBlankLines => "ends with blank lines",
+        })
+    }
+}
+
+/// Check the content of an input file.
+///
+/// Downloaded puzzle inputs end with a single newline, which is only checked if `strict` is set.
+/// Examples are often written by hand and are not held to that.
+pub fn check(content: &str, strict: bool) -> Option<Problem> {
+    if content.trim().is_empty() {
+        return Some(Problem::Empty);
+    }
+
+    if content.starts_with('\u{feff}') {
+        return Some(Problem::ByteOrderMark);
+    }
+
+    if content.contains("\r\n") {
+        return Some(Problem::CarriageReturns);
+    }
+
+    if !strict {
+        return None;
+    }
+
+    match content.strip_suffix('\n') {
+        None => Some(Problem::MissingFinalNewline),
+        Some(rest) if rest.ends_with('\n') => Some(Problem::TrailingBlankLines),
+        Some(_) => None,
+    }
+}
+
+/// Exit code of a solution whose input is missing or damaged, which tells it apart from a panic.
+pub const BAD_INPUT_EXIT_CODE: i32 = 3;
+
+/// Read and check `data/<folder>/<file_name>`. The error explains what is wrong with the file and
+/// which command fixes it. Problems that are not fatal are printed as a warning.
+pub fn read(folder: &str, day: Day, file_name: &str) -> Result<String, String> {
+    let path = Path::new("data").join(folder).join(file_name);
+    let describe = |problem: Problem| {
+        format!(
+            "\"{}\" {problem}. {}",
+            path.display(),
+            suggestion(folder, day)
+        )
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(content) => match check(&content, folder == "inputs") {
+            Some(problem) if problem.is_fatal_for(folder) => Err(describe(problem)),
+            Some(problem) => {
+                eprintln!("{ANSI_ITALIC}Warning: {}{ANSI_RESET}", describe(problem));
+                Ok(content)
+            }
+            None => Ok(content),
+        },
+        Err(e) if e.kind() == ErrorKind::NotFound => Err(describe(Problem::Missing)),
+        Err(e) => Err(format!("could not read \"{}\": {e}", path.display())),
+    }
+}
+
+fn suggestion(folder: &str, day: Day) -> String {
+    match folder {
+        "inputs" => format!("Run `cargo download {day}` to download your puzzle input."),
+        "examples" => format!(
+            "Run `cargo examples {day} --overwrite` to extract the examples from the puzzle description, or fix the file by hand."
+        ),
+        _ => "Please check the file.".into(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, read, Problem};
+    use crate::day;
+
+    #[test]
+    fn accepts_valid_inputs() {
+        assert_eq!(check("1 2\n3 4\n", true), None);
+        // leading whitespace and trailing spaces within lines are part of some puzzles.
+        assert_eq!(check("    [D]    \n[N] [C]    \n", true), None);
+    }
+
+    #[test]
+    fn detects_problems() {
+        assert_eq!(check("", true), Some(Problem::Empty));
+        assert_eq!(check(" \n\n", false), Some(Problem::Empty));
+        assert_eq!(check("\u{feff}1 2\n", false), Some(Problem::ByteOrderMark));
+        assert_eq!(
+            check("1 2\r\n3 4\r\n", false),
+            Some(Problem::CarriageReturns)
+        );
+        assert_eq!(check("1 2\n3", true), Some(Problem::MissingFinalNewline));
+        assert_eq!(check("1 2\n\n", true), Some(Problem::TrailingBlankLines));
+    }
+
+    #[test]
+    fn only_stops_for_fatal_problems() {
+        assert!(Problem::Empty.is_fatal());
+        assert!(Problem::CarriageReturns.is_fatal());
+        assert!(!Problem::MissingFinalNewline.is_fatal());
+    }
+
+    #[test]
+    fn allows_empty_examples() {
+        assert!(!Problem::Empty.is_fatal_for("examples"));
+        assert!(Problem::Empty.is_fatal_for("inputs"));
+        assert!(Problem::CarriageReturns.is_fatal_for("examples"));
+    }
+
+    #[test]
+    fn relaxes_checks_for_examples() {
+        assert_eq!(check("1 2\n3", false), None);
+        assert_eq!(check("1 2\n\n", false), None);
+    }
+
+    #[test]
+    fn suggests_fixes() {
+        let err = read("inputs", day!(25), "does-not-exist.txt").unwrap_err();
+        assert!(err.contains("does not exist"));
+        assert!(err.contains("cargo download 25"));
+
+        let err = read("examples", day!(25), "does-not-exist.txt").unwrap_err();
+        assert!(err.contains("cargo examples 25"));
+    }
+}
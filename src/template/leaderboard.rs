@@ -0,0 +1,514 @@
+/// Viewer for the JSON export of a private leaderboard, as printed by `cargo leaderboard`.
+///
+/// The export can be downloaded from the "API" link on the page of a private leaderboard.
+use std::{collections::HashMap, fmt::Write, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    all_days,
+    clock::{self, format_seconds},
+    Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// How members are ranked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Scoring {
+    /// Points per star, computed from the star timestamps of all members.
+    /// The first member to get a star receives one point per member, the next one point less.
+    #[default]
+    Local,
+    /// Points on the global leaderboard, as reported by the export.
+    Global,
+}
+
+impl FromStr for Scoring {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local" => Ok(Scoring::Local),
+            "global" => Ok(Scoring::Global),
+            _ => Err(format!(
+                "unknown scoring `{s}`, expected one of: local, global."
+            )),
+        }
+    }
+}
+
+/// A star earned by a member.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Star {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Order in which stars were earned across all of Advent of Code, used to break ties.
+    pub index: u64,
+}
+
+/// A member of a private leaderboard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// Members that did not set a name are anonymous.
+    pub name: Option<String>,
+    pub stars: u64,
+    pub local_score: u64,
+    pub global_score: u64,
+    /// Stars per day and part.
+    pub completion: HashMap<Day, [Option<Star>; 2]>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn star(&self, day: Day, part: u8) -> Option<Star> {
+        let index = usize::from(part.checked_sub(1)?);
+        *self.completion.get(&day)?.get(index)?
+    }
+}
+
+/// A private leaderboard of a single event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    /// Members, sorted by id.
+    pub members: Vec<Member>,
+}
+
+/// A member's position on the leaderboard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ranking<'a> {
+    pub rank: usize,
+    pub score: u64,
+    pub member: &'a Member,
+}
+
+impl Leaderboard {
+    /// Local score of every member by id, computed from the star timestamps.
+    pub fn local_scores(&self) -> HashMap<u64, u64> {
+        let mut scores: HashMap<u64, u64> = self.members.iter().map(|m| (m.id, 0)).collect();
+        let points = self.members.len() as u64;
+
+        for day in all_days() {
+            for part in [1, 2] {
+                let mut stars: Vec<(Star, u64)> = self
+                    .members
+                    .iter()
+                    .filter_map(|m| Some((m.star(day, part)?, m.id)))
+                    .collect();
+
+                stars.sort_by_key(|(star, _)| (star.timestamp, star.index));
+
+                for (position, (_, id)) in stars.into_iter().enumerate() {
+                    *scores.entry(id).or_default() += points - position as u64;
+                }
+            }
+        }
+
+        scores
+    }
+
+    /// Members ordered by score, then by stars and by who got their last star first.
+    /// Members with the same score and stars share a rank.
+    pub fn rankings(&self, scoring: Scoring) -> Vec<Ranking<'_>> {
+        let local_scores = self.local_scores();
+        let last_star = |m: &Member| {
+            m.completion
+                .values()
+                .flatten()
+                .flatten()
+                .map(|s| s.timestamp)
+                .max()
+                .unwrap_or(u64::MAX)
+        };
+
+        let mut rankings: Vec<Ranking> = self
+            .members
+            .iter()
+            .map(|member| Ranking {
+                rank: 0,
+                score: match scoring {
+                    Scoring::Local => local_scores[&member.id],
+                    Scoring::Global => member.global_score,
+                },
+                member,
+            })
+            .collect();
+
+        rankings.sort_by_key(|r| {
+            (
+                std::cmp::Reverse(r.score),
+                std::cmp::Reverse(r.member.stars),
+                last_star(r.member),
+                r.member.id,
+            )
+        });
+
+        let mut previous: Option<(u64, u64, usize)> = None;
+        for (i, ranking) in rankings.iter_mut().enumerate() {
+            ranking.rank = match previous {
+                Some((score, stars, rank))
+                    if score == ranking.score && stars == ranking.member.stars =>
+                {
+                    rank
+                }
+                _ => i + 1,
+            };
+            previous = Some((ranking.score, ranking.member.stars, ranking.rank));
+        }
+
+        rankings
+    }
+
+    /// Seconds since the unix epoch at which the puzzle of a day unlocked.
+    pub fn unlock_timestamp(&self, day: Day) -> Option<u64> {
+        clock::unlock_timestamp(self.event.parse().ok()?, day)
+    }
+
+    /// Days on which at least one member earned a star.
+    pub fn days(&self) -> Vec<Day> {
+        all_days()
+            .filter(|day| self.members.iter().any(|m| m.star(*day, 1).is_some()))
+            .collect()
+    }
+}
+
+/// Render the ranked table of all members.
+pub fn render_rankings(leaderboard: &Leaderboard, scoring: Scoring) -> String {
+    let mut output = String::new();
+    let rankings = leaderboard.rankings(scoring);
+    let scoring = match scoring {
+        Scoring::Local => "local score",
+        Scoring::Global => "global score",
+    };
+
+    writeln!(
+        output,
+        "{ANSI_BOLD}Private leaderboard {} ({scoring}){ANSI_RESET}",
+        leaderboard.event
+    )
+    .unwrap();
+    writeln!(output).unwrap();
+
+    let tens: String = all_days()
+        .map(|d| match d.into_inner() / 10 {
+            0 => ' ',
+            tens => digit(tens),
+        })
+        .collect();
+    let ones: String = all_days().map(|d| digit(d.into_inner() % 10)).collect();
+    writeln!(output, "{:20}{tens}", "").unwrap();
+    writeln!(output, "{:>4}  {:>5}  {:>5}  {ones}", "#", "Score", "Stars").unwrap();
+
+    for ranking in &rankings {
+        let calendar: String = all_days()
+            .map(|day| {
+                match (
+                    ranking.member.star(day, 1).is_some(),
+                    ranking.member.star(day, 2).is_some(),
+                ) {
+                    (true, true) => '★',
+                    (true, false) => '☆',
+                    _ => '·',
+                }
+            })
+            .collect();
+
+        writeln!(
+            output,
+            "{:>4}  {:>5}  {:>5}  {calendar}  {}",
+            format!("{})", ranking.rank),
+            ranking.score,
+            ranking.member.stars,
+            ranking.member.display_name()
+        )
+        .unwrap();
+    }
+
+    writeln!(output).unwrap();
+    writeln!(output, "★ both parts · ☆ first part only").unwrap();
+
+    output
+}
+
+/// Render the time every member needed for each part of a day, counted from the unlock of the
+/// puzzle, and the time between both parts.
+pub fn render_day(leaderboard: &Leaderboard, day: Day) -> String {
+    let mut output = String::new();
+    let unlock = leaderboard.unlock_timestamp(day).unwrap_or_default();
+    let elapsed = |star: Option<Star>| star.map(|s| s.timestamp.saturating_sub(unlock));
+
+    let mut members: Vec<&Member> = leaderboard
+        .members
+        .iter()
+        .filter(|m| m.star(day, 1).is_some())
+        .collect();
+
+    members.sort_by_key(|m| {
+        (
+            m.star(day, 2)
+                .map_or((u64::MAX, u64::MAX), |s| (s.timestamp, s.index)),
+            m.star(day, 1).map(|s| (s.timestamp, s.index)),
+        )
+    });
+
+    writeln!(output, "{ANSI_BOLD}Day {}{ANSI_RESET}", day.into_inner()).unwrap();
+
+    if members.is_empty() {
+        writeln!(output, "{ANSI_ITALIC}No stars yet.{ANSI_RESET}").unwrap();
+        return output;
+    }
+
+    let width = members
+        .iter()
+        .map(|m| m.display_name().chars().count())
+        .max()
+        .unwrap_or_default();
+
+    writeln!(
+        output,
+        "{:width$}  {:>12}  {:>12}  {:>12}",
+        "", "Part 1", "Part 2", "Delta"
+    )
+    .unwrap();
+
+    for member in members {
+        let part_1 = elapsed(member.star(day, 1));
+        let part_2 = elapsed(member.star(day, 2));
+        let delta = part_1
+            .zip(part_2)
+            .map(|(part_1, part_2)| part_2.saturating_sub(part_1));
+        let cell = |seconds: Option<u64>| seconds.map_or_else(|| "-".into(), format_seconds);
+
+        writeln!(
+            output,
+            "{:width$}  {:>12}  {:>12}  {:>12}",
+            member.display_name(),
+            cell(part_1),
+            cell(part_2),
+            delta.map_or_else(|| "-".into(), |d| format!("+{}", format_seconds(d))),
+        )
+        .unwrap();
+    }
+
+    output
+}
+
+fn digit(value: u8) -> char {
+    char::from_digit(u32::from(value), 10).unwrap_or(' ')
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<&JsonValue> for Star {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected star to be a JSON object.")?;
+
+        Ok(Star {
+            timestamp: number(json, "get_star_ts", "star")?,
+            index: number(json, "star_index", "star")?,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        let name = match json.get("name") {
+            Some(JsonValue::String(name)) => Some(name.clone()),
+            Some(JsonValue::Null) | None => None,
+            Some(_) => return Err("Expected member.name to be a string or null.".into()),
+        };
+
+        let days = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected member.completion_day_level to be an object.")?;
+
+        let mut completion = HashMap::new();
+
+        for (day, parts) in days {
+            let day = Day::from_str(day).or(Err("Expected completion keys to be days."))?;
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected completion of a day to be an object.")?;
+
+            let star = |part: &str| parts.get(part).map(Star::try_from).transpose();
+            completion.insert(day, [star("1")?, star("2")?]);
+        }
+
+        Ok(Member {
+            id: number(json, "id", "member")?,
+            name,
+            stars: number(json, "stars", "member")?,
+            local_score: number(json, "local_score", "member")?,
+            global_score: number(json, "global_score", "member")?,
+            completion,
+        })
+    }
+}
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        // exports of older events store the event as a number.
+        let event = match json.get("event") {
+            Some(JsonValue::String(event)) => event.clone(),
+            Some(JsonValue::Number(event)) => event.to_string(),
+            _ => return Err("Expected leaderboard.event to be a string.".into()),
+        };
+
+        let mut members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected leaderboard.members to be an object.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        members.sort_by_key(|m| m.id);
+
+        Ok(Leaderboard {
+            event,
+            owner_id: number(json, "owner_id", "leaderboard")?,
+            members,
+        })
+    }
+}
+
+/// Read a non-negative integer from a JSON object.
+fn number(json: &HashMap<String, JsonValue>, key: &str, name: &str) -> Result<u64, String> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    json.get(key)
+        .and_then(|v| v.get::<f64>())
+        .filter(|n| **n >= 0.0)
+        .map(|n| *n as u64)
+        .ok_or(format!("Expected {name}.{key} to be a number."))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render_day, render_rankings, Leaderboard, Scoring};
+    use crate::day;
+
+    const FIXTURE: &str = include_str!("fixtures/leaderboard.json");
+    const EMPTY_FIXTURE: &str = include_str!("fixtures/leaderboard_empty.json");
+
+    fn leaderboard(json: &str) -> Leaderboard {
+        Leaderboard::try_from(json.to_string()).unwrap()
+    }
+
+    #[test]
+    fn parses_export() {
+        let leaderboard = leaderboard(FIXTURE);
+        assert_eq!(leaderboard.event, "2024");
+        assert_eq!(leaderboard.owner_id, 101);
+        assert_eq!(leaderboard.members.len(), 3);
+
+        let alice = &leaderboard.members[0];
+        assert_eq!(alice.display_name(), "Alice");
+        assert_eq!(alice.star(day!(2), 2).unwrap().timestamp, 1_733_122_000);
+        assert_eq!(leaderboard.members[1].star(day!(2), 2), None);
+        assert_eq!(
+            leaderboard.members[2].display_name(),
+            "(anonymous user #303)"
+        );
+        assert_eq!(leaderboard.days(), vec![day!(1), day!(2)]);
+    }
+
+    #[test]
+    fn computes_local_scores() {
+        let leaderboard = leaderboard(FIXTURE);
+        let scores = leaderboard.local_scores();
+
+        // computed scores agree with the ones reported by the export.
+        for member in &leaderboard.members {
+            assert_eq!(scores[&member.id], member.local_score);
+        }
+    }
+
+    #[test]
+    fn ranks_members() {
+        let leaderboard = leaderboard(FIXTURE);
+
+        let local: Vec<(usize, u64)> = leaderboard
+            .rankings(Scoring::Local)
+            .iter()
+            .map(|r| (r.rank, r.member.id))
+            .collect();
+        assert_eq!(local, vec![(1, 101), (2, 202), (3, 303)]);
+
+        let global: Vec<(usize, u64)> = leaderboard
+            .rankings(Scoring::Global)
+            .iter()
+            .map(|r| (r.rank, r.member.id))
+            .collect();
+        assert_eq!(global, vec![(1, 202), (2, 101), (3, 303)]);
+    }
+
+    #[test]
+    fn computes_unlock_timestamps() {
+        let leaderboard = leaderboard(FIXTURE);
+        assert_eq!(leaderboard.unlock_timestamp(day!(1)), Some(1_733_029_200));
+        assert_eq!(leaderboard.unlock_timestamp(day!(2)), Some(1_733_115_600));
+    }
+
+    #[test]
+    fn renders_rankings() {
+        let output = render_rankings(&leaderboard(FIXTURE), Scoring::Local);
+        assert!(output.contains("  1)     10      4  ★★·······················  Alice"));
+        assert!(output.contains("  2)      8      3  ★☆·······················  Bob"));
+    }
+
+    #[test]
+    fn renders_days() {
+        let leaderboard = leaderboard(FIXTURE);
+
+        let output = render_day(&leaderboard, day!(1));
+        let lines: Vec<&str> = output.lines().skip(2).collect();
+        assert!(lines[0].starts_with("Bob"));
+        assert!(lines[0].ends_with("00:01:40      00:13:20     +00:11:40"));
+        assert!(lines[2].contains("1d 23:26:40"));
+
+        let output = render_day(&leaderboard, day!(3));
+        assert!(output.contains("No stars yet."));
+    }
+
+    #[test]
+    fn handles_empty_leaderboards() {
+        let leaderboard = leaderboard(EMPTY_FIXTURE);
+        assert!(leaderboard.days().is_empty());
+
+        let rankings = leaderboard.rankings(Scoring::Local);
+        assert_eq!(rankings.len(), 1);
+        assert_eq!(rankings[0].score, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_exports() {
+        leaderboard(r#"{ "event": "2024", "owner_id": 1, "members": { "1": { "id": 1 } } }"#);
+    }
+}
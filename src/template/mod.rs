@@ -1,12 +1,29 @@
-use std::{env, fs};
-
+pub mod answers;
 pub mod aoc_cli;
+pub mod cli;
+pub mod clock;
 pub mod commands;
+pub mod compare;
+pub mod history;
+pub mod input;
+pub mod leaderboard;
+pub mod params;
+pub mod protocol;
+pub mod puzzle;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod scaffolds;
+pub mod stats;
+pub mod status;
+pub mod submissions;
+pub mod watch;
 
 pub use day::*;
+pub use error::Error;
 
 mod day;
+mod error;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
@@ -14,55 +31,137 @@ mod timings;
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 
 /// Helper function that reads a text file to a string.
+/// Panics with an explanation if the file is missing or damaged, see [`input::check`].
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    input::read(folder, day, &format!("{day}.txt")).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Panics with an explanation if the file is missing or damaged, see [`input::check`].
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    input::read(folder, day, &format!("{day}-{part}.txt")).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// At run time, the `--part` option restricts the binary to a single part as well.
+///
+/// Solutions can declare named parameters with defaults instead, e.g. a grid size that differs
+/// between the example and the real input:
+///
+/// ```ignore
+/// advent_of_code::solution!(14, params { size: (i32, i32) = (101, 103) });
+///
+/// pub fn part_one(input: &str, params: &Params) -> Option<usize> { ... }
+/// ```
+///
+/// This generates a `Params` struct that is passed to both parts. Defaults are overridden by a
+/// sidecar file next to the input, e.g. `data/examples/14.params` containing `size=11,7`, and by
+/// `--param size=11,7` on the command line. Tests can read the sidecar with `Params::read("examples")`.
+///
+/// Besides `main`, the macro generates `run_parts` and the `SOLUTION` constant, which the
+/// `solutions` binary uses to run the day in-process.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, params: (), [part_one, 1] [part_two, 2]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, params: (), [part_one, 1]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, params: (), [part_two, 2]);
     };
+    ($day:expr, params { $( $name:ident : $ty:ty = $default:expr ),* $(,)? }) => {
+        /// Parameters of this solution, see `solution!`.
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct Params {
+            $( pub $name: $ty, )*
+        }
+
+        impl Default for Params {
+            fn default() -> Self {
+                Params {
+                    $( $name: $default, )*
+                }
+            }
+        }
+
+        impl $crate::template::params::SolutionParams for Params {
+            fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+                match name {
+                    $(
+                        stringify!($name) => {
+                            self.$name = $crate::template::params::ParamValue::parse_param(value)
+                                .map_err(|e| format!("invalid value for `{name}`: {e}"))?;
+                        }
+                    )*
+                    _ => return Err(format!("unknown parameter `{name}`.")),
+                }
+                Ok(())
+            }
+        }
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        impl Params {
+            /// Read the parameters next to a data file, e.g. `data/examples/<day>.params`.
+            pub fn read(folder: &str) -> Self {
+                $crate::template::params::read_params(folder, DAY)
+            }
+
+            /// Read the parameters next to a data file with a part suffix, e.g. `data/examples/<day>-2.params`.
+            pub fn read_part(folder: &str, part: u8) -> Self {
+                $crate::template::params::read_params_part(folder, DAY, part)
+            }
+        }
+
+        $crate::solution!(
+            @impl $day,
+            params: Params,
+            [|input| part_one(input, &params), 1] [|input| part_two(input, &params), 2]
+        );
+    };
+
+    (@impl $day:expr, $params:ident: $params_ty:ty, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// Registry entry for running this day in-process.
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution { day: DAY, run: run_parts };
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
-        fn main() {
+        /// Runs every part of the solution against `input`, appending a record per finished part.
+        /// Records are kept in `records` even if a later part panics.
+        pub fn run_parts(
+            input: &str,
+            options: &$crate::template::runner::RunOptions,
+            records: &mut Vec<$crate::template::protocol::PartRecord>,
+        ) -> Result<(), $crate::template::Error> {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let $params: $params_ty = load_params(DAY, options)?;
+            $(
+                if options.runs_part($part) {
+                    records.push(run_part($func, input, DAY, $part, options)?);
+                }
+            )*
+            Ok(())
+        }
+
+        fn main() {
+            if let Err(e) = $crate::template::runner::run_bin(DAY, run_parts) {
+                eprintln!("{e}");
+                std::process::exit(e.exit_code());
+            }
         }
     };
 }
@@ -0,0 +1,237 @@
+/// Named solution parameters, e.g. a grid size that differs between the example and the real input.
+///
+/// Parameters are declared in the `solution!` macro. Their defaults can be overridden by a sidecar
+/// file next to the input (`data/examples/14.params` for `data/examples/14.txt`) and by passing
+/// `--param name=value` on the command line.
+use std::{
+    env, fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use crate::template::Day;
+
+/// Raw `name=value` pairs, in the order they were read.
+pub type Values = Vec<(String, String)>;
+
+/// A value that can be used as a solution parameter.
+pub trait ParamValue: Sized {
+    fn parse_param(value: &str) -> Result<Self, String>;
+}
+
+macro_rules! impl_param_value {
+    ($($ty:ty),*) => {
+        $(
+            impl ParamValue for $ty {
+                fn parse_param(value: &str) -> Result<Self, String> {
+                    value
+                        .trim()
+                        .parse()
+                        .map_err(|_| format!("expected {}, got `{value}`.", stringify!($ty)))
+                }
+            }
+        )*
+    };
+}
+
+impl_param_value!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, bool, char, String);
+
+impl<A: ParamValue, B: ParamValue> ParamValue for (A, B) {
+    fn parse_param(value: &str) -> Result<Self, String> {
+        match value.split(',').collect::<Vec<_>>()[..] {
+            [a, b] => Ok((A::parse_param(a)?, B::parse_param(b)?)),
+            _ => Err(format!(
+                "expected two comma-separated values, got `{value}`."
+            )),
+        }
+    }
+}
+
+impl<A: ParamValue, B: ParamValue, C: ParamValue> ParamValue for (A, B, C) {
+    fn parse_param(value: &str) -> Result<Self, String> {
+        match value.split(',').collect::<Vec<_>>()[..] {
+            [a, b, c] => Ok((A::parse_param(a)?, B::parse_param(b)?, C::parse_param(c)?)),
+            _ => Err(format!(
+                "expected three comma-separated values, got `{value}`."
+            )),
+        }
+    }
+}
+
+impl<T: ParamValue> ParamValue for Vec<T> {
+    fn parse_param(value: &str) -> Result<Self, String> {
+        value.split(',').map(T::parse_param).collect()
+    }
+}
+
+/// Parameters of a solution. Implemented by the `Params` struct the `solution!` macro generates.
+pub trait SolutionParams: Default {
+    /// Override a single parameter by name.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+    /// Apply `values` on top of the defaults.
+    fn from_values(values: &Values) -> Result<Self, String> {
+        let mut params = Self::default();
+        for (name, value) in values {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+/// Days without parameters. Any override is an error.
+impl SolutionParams for () {
+    fn set(&mut self, name: &str, _: &str) -> Result<(), String> {
+        Err(format!(
+            "unknown parameter `{name}`, this solution has no parameters."
+        ))
+    }
+}
+
+/// Parse the contents of a sidecar file, one `name=value` pair per line.
+/// Blank lines and lines starting with `#` are ignored.
+pub fn parse_values(contents: &str) -> Result<Values, String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_value)
+        .collect()
+}
+
+/// Parse a single `name=value` pair.
+pub fn parse_value(pair: &str) -> Result<(String, String), String> {
+    match pair.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!(
+            "expected a parameter like `name=value`, got `{pair}`."
+        )),
+    }
+}
+
+/// Read the sidecar file at `path`. A missing file means no overrides, a file that exists but
+/// cannot be read is an error rather than a silent fallback to the defaults.
+pub fn read_values(path: &Path) -> Result<Values, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse_values(&contents)
+            .map_err(|e| format!("invalid parameter file \"{}\": {e}", path.display())),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(format!(
+            "could not read parameter file \"{}\": {e}",
+            path.display()
+        )),
+    }
+}
+
+/// Path of the sidecar file that belongs to an input file, e.g. `data/examples/14.params`.
+pub fn sidecar_path(input_path: &Path) -> PathBuf {
+    input_path.with_extension("params")
+}
+
+/// Helper function that reads the parameters next to a data file, like `read_file` reads the file.
+#[must_use]
+pub fn read_params<P: SolutionParams>(folder: &str, day: Day) -> P {
+    read_params_at(&format!("{day}.txt"), folder)
+}
+
+/// Helper function that reads the parameters next to a data file with a part suffix, like `read_file_part`.
+#[must_use]
+pub fn read_params_part<P: SolutionParams>(folder: &str, day: Day, part: u8) -> P {
+    read_params_at(&format!("{day}-{part}.txt"), folder)
+}
+
+fn read_params_at<P: SolutionParams>(file: &str, folder: &str) -> P {
+    let cwd = env::current_dir().unwrap();
+    let path = sidecar_path(&cwd.join("data").join(folder).join(file));
+    read_values(&path)
+        .and_then(|values| P::from_values(&values))
+        .expect("could not read parameter file")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_values, read_values, ParamValue, SolutionParams};
+    use std::{env, fs, path::Path};
+
+    #[derive(Debug, PartialEq)]
+    struct Params {
+        size: (usize, usize),
+        gates: Vec<String>,
+    }
+
+    impl Default for Params {
+        fn default() -> Self {
+            Params {
+                size: (71, 71),
+                gates: vec![],
+            }
+        }
+    }
+
+    impl SolutionParams for Params {
+        fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+            match name {
+                "size" => self.size = ParamValue::parse_param(value)?,
+                "gates" => self.gates = ParamValue::parse_param(value)?,
+                _ => return Err(format!("unknown parameter `{name}`.")),
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn parses_values() {
+        assert_eq!(i32::parse_param(" -4 "), Ok(-4));
+        assert_eq!(<(i32, i32)>::parse_param("11,7"), Ok((11, 7)));
+        assert!(<(i32, i32)>::parse_param("11").is_err());
+        assert_eq!(
+            Vec::<String>::parse_param("z00,z01"),
+            Ok(vec!["z00".to_string(), "z01".to_string()])
+        );
+        assert!(usize::parse_param("-1").is_err());
+    }
+
+    #[test]
+    fn parses_sidecar_files() {
+        let values = parse_values("# example grid\nsize = 7,7\n\ngates=z00\n").unwrap();
+        assert_eq!(
+            values,
+            vec![
+                ("size".to_string(), "7,7".to_string()),
+                ("gates".to_string(), "z00".to_string())
+            ]
+        );
+        assert!(parse_values("size").is_err());
+    }
+
+    #[test]
+    fn applies_values_to_defaults() {
+        let params = Params::from_values(&vec![("size".into(), "7,7".into())]).unwrap();
+        assert_eq!(params.size, (7, 7));
+        assert!(params.gates.is_empty());
+
+        assert!(Params::from_values(&vec![("other".into(), "1".into())]).is_err());
+        assert!(<()>::from_values(&vec![("size".into(), "1".into())]).is_err());
+    }
+
+    #[test]
+    fn only_ignores_missing_sidecar_files() {
+        assert_eq!(
+            read_values(Path::new("data/examples/does-not-exist.params")),
+            Ok(vec![])
+        );
+
+        let path = env::temp_dir().join(format!("aoc-params-{}.params", std::process::id()));
+        fs::write(&path, [0xff, 0xfe]).unwrap();
+        let result = read_values(&path);
+        let _ = fs::remove_file(&path);
+
+        assert!(result
+            .unwrap_err()
+            .starts_with("could not read parameter file"));
+    }
+}
@@ -0,0 +1,261 @@
+/// Machine-readable protocol between solution binaries and the multi-day runner.
+///
+/// When the `AOC_REPORT_FILE` environment variable is set, every call to `runner::run_part`
+/// appends one JSON object per line to that file. The pretty terminal output is unaffected,
+/// so solutions are free to print their own debug output.
+use std::{
+    collections::HashMap,
+    env, fs,
+    fs::OpenOptions,
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    stats::{BenchStats, HeapStats},
+    Day,
+};
+
+/// Version of the record format. Bumped on breaking changes.
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// Name of the environment variable that selects the file records are written to.
+pub const REPORT_FILE_ENV: &str = "AOC_REPORT_FILE";
+
+/// Outcome of running a single part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The part returned `Some(answer)`.
+    Solved,
+    /// The part returned `None`.
+    Unsolved,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+        }
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Status::Solved),
+            "unsolved" => Ok(Status::Unsolved),
+            x => Err(format!("unknown status `{x}`.")),
+        }
+    }
+}
+
+/// Result of running one part of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRecord {
+    pub day: Day,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// Execution time statistics, measured over one or more samples.
+    pub stats: BenchStats,
+    /// Heap usage of the first run, only measured in builds with the `dhat-heap` feature.
+    pub heap: Option<HeapStats>,
+}
+
+/// Appends a record to the report file, if one was requested via `AOC_REPORT_FILE`.
+pub fn emit(record: &PartRecord) {
+    let Ok(path) = env::var(REPORT_FILE_ENV) else {
+        return;
+    };
+
+    if let Err(e) = append_record(Path::new(&path), record) {
+        eprintln!("Failed to write report record to \"{path}\": {e}");
+    }
+}
+
+fn append_record(path: &Path, record: &PartRecord) -> io::Result<()> {
+    let line = JsonValue::from(record)
+        .stringify()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")
+}
+
+/// Reads all records from a report file. A missing file yields no records.
+pub fn read_records(path: &Path) -> Result<Vec<PartRecord>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse_records(&contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Parses JSON-lines report contents, skipping blank lines.
+pub fn parse_records(contents: &str) -> Result<Vec<PartRecord>, String> {
+    contents
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let json = JsonValue::from_str(l).or(Err("record is not valid JSON."))?;
+            PartRecord::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartRecord> for JsonValue {
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(PROTOCOL_VERSION)),
+        );
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+
+        if let Some(heap) = &value.heap {
+            map.insert("heap".into(), JsonValue::from(heap));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartRecord {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let version = json
+            .get("version")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.version to be a number.")?;
+
+        if version != f64::from(PROTOCOL_VERSION) {
+            return Err(format!(
+                "Unsupported record version {version}, expected {PROTOCOL_VERSION}."
+            ));
+        }
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.part to be a number.")? as u8;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.status to be a string.")?
+            .parse()?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected record.stats to be present.")
+            .map(BenchStats::try_from)??;
+
+        let heap = json.get("heap").map(HeapStats::try_from).transpose()?;
+
+        Ok(PartRecord {
+            day,
+            part,
+            status,
+            answer: answer.cloned(),
+            stats,
+            heap,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_records, PartRecord, Status};
+    use crate::{
+        day,
+        template::stats::{BenchStats, HeapStats},
+    };
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn roundtrips_records() {
+        let record = PartRecord {
+            day: day!(16),
+            part: 2,
+            status: Status::Solved,
+            answer: Some("4,6,3".into()),
+            stats: BenchStats {
+                samples: 100,
+                mean: 74.13,
+                min: 70.0,
+                median: 74.0,
+                p95: 80.5,
+                max: 90.0,
+                std_dev: 3.2,
+                outliers: 2,
+            },
+            heap: Some(HeapStats {
+                total_bytes: 276,
+                total_blocks: 3,
+                max_bytes: 232,
+            }),
+        };
+        let line = JsonValue::from(&record).stringify().unwrap();
+        assert_eq!(parse_records(&line).unwrap(), vec![record]);
+    }
+
+    #[test]
+    fn parses_unsolved_records() {
+        let contents = r#"{"version":2,"day":"03","part":1,"status":"unsolved","answer":null,"stats":{"samples":1,"mean":12,"min":12,"median":12,"p95":12,"max":12,"std_dev":0,"outliers":0}}
+
+"#;
+        let records = parse_records(contents).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].status, Status::Unsolved);
+        assert_eq!(records[0].answer, None);
+        assert_eq!(records[0].heap, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_unknown_versions() {
+        let contents = r#"{"version":1,"day":"03","part":1,"status":"solved","answer":"1","mean_nanos":12,"samples":1}"#;
+        parse_records(contents).unwrap();
+    }
+}
@@ -0,0 +1,372 @@
+/// Extracts examples and their expected answers from puzzle descriptions downloaded by aoc-cli.
+use crate::template::Day;
+
+/// A single part of a puzzle description.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PuzzlePart {
+    /// Code blocks that were introduced as an example.
+    pub examples: Vec<String>,
+    /// Last emphasized code value of the part, usually the answer for the example.
+    pub answer: Option<String>,
+}
+
+/// Examples of a puzzle, deduplicated across parts.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    /// Example inputs. The first one is stored as `NN.txt`, the following ones as `NN-2.txt` etc.
+    pub inputs: Vec<String>,
+    /// Per part, the 1-based index of its example and the expected answer.
+    pub parts: Vec<(usize, Option<String>)>,
+}
+
+/// Split a puzzle description into its parts.
+pub fn parse(markdown: &str) -> Vec<PuzzlePart> {
+    let mut parts = vec![PuzzlePart::default()];
+
+    let mut in_code_block = false;
+    let mut code_block: Vec<&str> = vec![];
+    let mut is_example = false;
+    let mut previous_text = "";
+    let mut is_answered = false;
+
+    for line in markdown.lines() {
+        let part = parts.last_mut().unwrap();
+
+        if line.trim_start().starts_with("```") {
+            if in_code_block && is_example && !is_answered {
+                let mut block = code_block.join("\n");
+                block.push('\n');
+                part.examples.push(block);
+            }
+
+            in_code_block = !in_code_block;
+            is_example = previous_text.to_lowercase().contains("example");
+            code_block.clear();
+            continue;
+        }
+
+        if in_code_block {
+            code_block.push(line);
+            continue;
+        }
+
+        if line.contains("--- Part Two ---") {
+            parts.push(PuzzlePart::default());
+            is_answered = false;
+            continue;
+        }
+
+        if line.starts_with("Your puzzle answer was") {
+            is_answered = true;
+            continue;
+        }
+
+        if !line.trim().is_empty() {
+            previous_text = line;
+
+            if !is_answered {
+                if let Some(answer) = emphasized_code(line).pop() {
+                    part.answer = Some(answer);
+                }
+            }
+        }
+    }
+
+    parts
+}
+
+/// Collect the examples of all parts, assigning each part the first example it introduced.
+/// Parts that do not introduce an example of their own use the first one.
+pub fn examples(parts: &[PuzzlePart]) -> Examples {
+    let mut result = Examples::default();
+
+    for part in parts {
+        let mut index = None;
+
+        for example in &part.examples {
+            let position = match result.inputs.iter().position(|e| e == example) {
+                Some(position) => position,
+                None => {
+                    result.inputs.push(example.clone());
+                    result.inputs.len() - 1
+                }
+            };
+            index.get_or_insert(position + 1);
+        }
+
+        result.parts.push((index.unwrap_or(1), part.answer.clone()));
+    }
+
+    result
+}
+
+/// Path of the n-th example of a day, as read by `read_file` and `read_file_part`.
+pub fn example_path(day: Day, index: usize) -> String {
+    if index == 1 {
+        format!("data/examples/{day}.txt")
+    } else {
+        format!("data/examples/{day}-{index}.txt")
+    }
+}
+
+/// Values formatted as emphasized code, e.g. `` `*42*` `` or ``*`42`*``.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let mut values = vec![];
+
+    for (open, close) in [("`*", "*`"), ("*`", "`*")] {
+        let mut rest = line;
+        while let Some(start) = rest.find(open) {
+            rest = &rest[start + open.len()..];
+            let Some(end) = rest.find(close) else {
+                break;
+            };
+            values.push((line.len() - rest.len(), rest[..end].to_string()));
+            rest = &rest[end + close.len()..];
+        }
+    }
+
+    values.sort();
+    values
+        .into_iter()
+        .map(|(_, value)| value)
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+/// Title of a puzzle, e.g. `Day 1: Historian Hysteria`.
+pub fn title(markdown: &str) -> Option<String> {
+    let line = markdown.lines().find(|line| line.contains("--- Day "))?;
+    let title = line
+        .trim()
+        .trim_start_matches('\\')
+        .trim_matches('-')
+        .trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// Type of the answer a part function returns inside its `Option`, e.g. `u64`.
+pub fn answer_type(source: &str, part: u8) -> Option<&str> {
+    let name = match part {
+        1 => "fn part_one(",
+        2 => "fn part_two(",
+        _ => return None,
+    };
+
+    let start = source.find(name)?;
+    let signature = &source[start..start + source[start..].find('{')?];
+    let returned = signature.split_once("->")?.1.trim();
+
+    returned.strip_prefix("Option<")?.strip_suffix('>')
+}
+
+/// Rust expression for an expected answer, as used in the `assert_eq!` of a test.
+/// Returns `None` if the answer does not compile against the answer type of the part.
+pub fn expected_value(answer: &str, answer_type: &str) -> Option<String> {
+    // a leading `+` parses as a number, but is not valid in a literal.
+    let fits = !answer.starts_with('+')
+        && match answer_type {
+            "u8" => answer.parse::<u8>().is_ok(),
+            "u16" => answer.parse::<u16>().is_ok(),
+            "u32" => answer.parse::<u32>().is_ok(),
+            "u64" => answer.parse::<u64>().is_ok(),
+            "u128" => answer.parse::<u128>().is_ok(),
+            "usize" => answer.parse::<usize>().is_ok(),
+            "i8" => answer.parse::<i8>().is_ok(),
+            "i16" => answer.parse::<i16>().is_ok(),
+            "i32" => answer.parse::<i32>().is_ok(),
+            "i64" => answer.parse::<i64>().is_ok(),
+            "i128" => answer.parse::<i128>().is_ok(),
+            "isize" => answer.parse::<isize>().is_ok(),
+            "String" => return Some(format!("Some({answer:?}.to_string())")),
+            "&str" | "&'static str" => return Some(format!("Some({answer:?})")),
+            _ => false,
+        };
+
+    fits.then(|| format!("Some({answer})"))
+}
+
+/// Fill the expected answer and example of a part into the test module of a solution.
+/// Only tests that still assert `None` are changed, so edited tests are left alone.
+/// Returns `None` if the answer does not fit the answer type of the part.
+pub fn fill_test(source: &str, part: u8, example: usize, answer: &str) -> Option<String> {
+    let name = match part {
+        1 => "fn test_part_one()",
+        2 => "fn test_part_two()",
+        _ => return Some(source.to_string()),
+    };
+
+    let Some(start) = source.find(name) else {
+        return Some(source.to_string());
+    };
+
+    let end = source[start..]
+        .find("#[test]")
+        .map_or(source.len(), |end| start + end);
+
+    let body = &source[start..end];
+
+    if !body.contains("assert_eq!(result, None);") {
+        return Some(source.to_string());
+    }
+
+    let expected = expected_value(answer, answer_type(source, part)?)?;
+
+    let mut body = body.replace(
+        "assert_eq!(result, None);",
+        &format!("assert_eq!(result, {expected});"),
+    );
+
+    if example > 1 {
+        body = body.replace(
+            "read_file(\"examples\", DAY)",
+            &format!("read_file_part(\"examples\", DAY, {example})"),
+        );
+    }
+
+    Some(format!("{}{body}{}", &source[..start], &source[end..]))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{answer_type, examples, expected_value, fill_test, parse, title};
+
+    const PUZZLE: &str = r"\--- Day 1: Historian Hysteria ---
+----------
+
+For example:
+
+```
+3   4
+4   3
+```
+
+After sorting, the lists look like this:
+
+```
+3   3
+4   4
+```
+
+In the example above, this is `*1*` + `*0*`, a total distance of `*11*`!
+
+Your puzzle answer was `1234`.
+
+\--- Part Two ---
+----------
+
+Here's a larger example:
+
+```
+1 2
+```
+
+So, in this example, the similarity score is `*31*` (`1 + 30`).
+
+Your puzzle answer was `5678`.
+";
+
+    const MODULE: &str = r#"pub fn part_one(input: &str) -> Option<u64> {
+        None
+    }
+
+    pub fn part_two(input: &str) -> Option<String> {
+        None
+    }
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+"#;
+
+    #[test]
+    fn parses_parts() {
+        let parts = parse(PUZZLE);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].examples, vec!["3   4\n4   3\n".to_string()]);
+        assert_eq!(parts[0].answer, Some("11".into()));
+        assert_eq!(parts[1].examples, vec!["1 2\n".to_string()]);
+        assert_eq!(parts[1].answer, Some("31".into()));
+    }
+
+    #[test]
+    fn parses_alternative_emphasis() {
+        let parts = parse("For example:\n\n```\nabc\n```\n\nThe result is *`4,6,3`*.\n");
+        assert_eq!(parts[0].answer, Some("4,6,3".into()));
+    }
+
+    #[test]
+    fn collects_examples() {
+        let result = examples(&parse(PUZZLE));
+        assert_eq!(result.inputs.len(), 2);
+        assert_eq!(
+            result.parts,
+            vec![(1, Some("11".into())), (2, Some("31".into()))]
+        );
+    }
+
+    #[test]
+    fn reuses_first_example() {
+        let puzzle = "For example:\n\n```\nabc\n```\n\nIt is `*1*`.\n\n--- Part Two ---\n\nNow it is `*2*`.\n";
+        let result = examples(&parse(puzzle));
+        assert_eq!(result.inputs, vec!["abc\n".to_string()]);
+        assert_eq!(
+            result.parts,
+            vec![(1, Some("1".into())), (1, Some("2".into()))]
+        );
+    }
+
+    #[test]
+    fn fills_tests() {
+        let module = fill_test(MODULE, 1, 1, "11").unwrap();
+        let module = fill_test(&module, 2, 2, "a,b").unwrap();
+
+        assert!(module.contains("assert_eq!(result, Some(11));"));
+        assert!(module.contains("assert_eq!(result, Some(\"a,b\".to_string()));"));
+        assert!(module.contains("read_file_part(\"examples\", DAY, 2)"));
+        assert_eq!(module.matches("read_file(\"examples\", DAY)").count(), 1);
+
+        // tests that were edited already are left alone.
+        assert_eq!(fill_test(&module, 1, 1, "12"), Some(module));
+    }
+
+    #[test]
+    fn skips_answers_of_another_type() {
+        assert_eq!(fill_test(MODULE, 1, 1, "4,6,3"), None);
+    }
+
+    #[test]
+    fn reads_answer_types() {
+        assert_eq!(answer_type(MODULE, 1), Some("u64"));
+        assert_eq!(answer_type(MODULE, 2), Some("String"));
+        assert_eq!(answer_type("fn part_one(input: &str) -> u64 {", 1), None);
+    }
+
+    #[test]
+    fn parses_title() {
+        assert_eq!(title(PUZZLE), Some("Day 1: Historian Hysteria".into()));
+        assert_eq!(title("no title here"), None);
+    }
+
+    #[test]
+    fn formats_expected_values() {
+        assert_eq!(expected_value("42", "u64"), Some("Some(42)".into()));
+        assert_eq!(expected_value("-42", "i32"), Some("Some(-42)".into()));
+        assert_eq!(
+            expected_value("4,6,3", "String"),
+            Some("Some(\"4,6,3\".to_string())".into())
+        );
+        assert_eq!(expected_value("4,6,3", "u64"), None);
+        assert_eq!(expected_value("-1", "usize"), None);
+        assert_eq!(expected_value("300", "u8"), None);
+    }
+}
@@ -2,6 +2,8 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
+use crate::template::history::{day_sparkline, TimingHistory};
+use crate::template::stats::{format_bytes, format_nanos, HeapStats};
 use crate::template::timings::Timings;
 use crate::template::Day;
 
@@ -52,26 +54,64 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(
+    prefix: &str,
+    timings: Timings,
+    total_millis: f64,
+    history: Option<&TimingHistory>,
+) -> String {
     let header = format!("{prefix} Benchmarks");
+    // memory columns are only shown once a day was profiled with `cargo solve --dhat`.
+    let has_heap = timings.has_heap();
+
+    let mut columns = vec!["Day", "Part 1", "Part 2"];
+    if has_heap {
+        columns.extend(["Part 1 heap", "Part 2 heap"]);
+    }
+    if history.is_some() {
+        columns.push("Trend");
+    }
 
     let mut lines: Vec<String> = vec![
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", columns.join(" | ")),
+        format!("|{} |", " :---: |".repeat(columns.len() - 1) + " :---: "),
     ];
 
     for timing in timings.data {
         let path = get_path_for_bin(timing.day);
-        lines.push(format!(
+        let missing = if timing.timed_out { "timed out" } else { "-" };
+        let mut line = format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+            timing
+                .part_nanos(1)
+                .map_or_else(|| missing.into(), format_nanos),
+            timing
+                .part_nanos(2)
+                .map_or_else(|| missing.into(), format_nanos)
+        );
+
+        if has_heap {
+            line.push_str(&format!(
+                " {} | {} |",
+                format_heap(timing.part_1_heap.as_ref()),
+                format_heap(timing.part_2_heap.as_ref())
+            ));
+        }
+
+        if let Some(history) = history {
+            let trend = day_sparkline(history, timing.day);
+            line.push_str(&format!(
+                " {} |",
+                trend.map_or_else(|| "-".into(), |t| format!("`{t}`"))
+            ));
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
@@ -81,18 +121,38 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+/// Format the peak heap usage and allocation count of a part as a table cell.
+fn format_heap(heap: Option<&HeapStats>) -> String {
+    match heap {
+        Some(heap) => format!(
+            "`{}` in {} alloc{}",
+            format_bytes(heap.max_bytes),
+            heap.total_blocks,
+            if heap.total_blocks == 1 { "" } else { "s" }
+        ),
+        None => "-".into(),
+    }
+}
+
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    history: Option<&TimingHistory>,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings, total_millis, history);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Rewrite the benchmark table in the readme.
+/// A trend column with the recent runs of each day is added if a `history` is passed.
+pub fn update(timings: Timings, history: Option<&TimingHistory>) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, timings, total_millis, history)?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -100,28 +160,40 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::history::{HistoryEntry, RunContext, TimingHistory},
+        template::stats::{BenchStats, HeapStats},
+        template::timings::Timing,
+        template::timings::Timings,
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    part_1_stats: Some(BenchStats::from_mean(10_000_000.0)),
+                    part_2_stats: Some(BenchStats::from_mean(20_000_000.0)),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    timed_out: false,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    part_1_stats: Some(BenchStats::from_mean(30_000_000.0)),
+                    part_2_stats: Some(BenchStats::from_mean(40_000_000.0)),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    timed_out: false,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    part_1_stats: Some(BenchStats::from_mean(40_000_000.0)),
+                    part_2_stats: Some(BenchStats::from_mean(50_000_000.0)),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    timed_out: false,
                 },
             ],
         }
@@ -131,28 +203,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
+        assert!(s.contains("## Benchmarks"));
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +232,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -169,9 +241,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +252,62 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_heap() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_heap = Some(HeapStats {
+            total_bytes: 4096,
+            total_blocks: 3,
+            max_bytes: 1536,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0, None).unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(
+            lines[3],
+            "| Day | Part 1 | Part 2 | Part 1 heap | Part 2 heap |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` | `1.5 KiB` in 3 allocs | - |"
+        );
+        assert_eq!(
+            lines[6],
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` | - | - |"
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_with_trend() {
+        let entry = |timestamp: u64, nanos: f64| HistoryEntry {
+            context: RunContext {
+                commit: "abc1234".into(),
+                timestamp,
+                machine: "box".into(),
+                profile: "release".into(),
+            },
+            day: day!(1),
+            part_1_nanos: Some(nanos),
+            part_2_nanos: None,
+        };
+        let history = TimingHistory {
+            data: vec![entry(1, 30.0), entry(2, 10.0), entry(3, 20.0)],
+        };
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0, Some(&history)).unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(lines[3], "| Day | Part 1 | Part 2 | Trend |");
+        assert_eq!(lines[4], "| :---: | :---: | :---: | :---:  |");
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` | `█▁▅` |"
+        );
+        assert_eq!(
+            lines[6],
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` | - |"
+        );
+    }
 }
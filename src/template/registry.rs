@@ -0,0 +1,204 @@
+/// Registry of solutions compiled into the combined `solutions` binary.
+///
+/// Every `solution!` invocation exposes a `SOLUTION` constant. With the `registry` feature, the
+/// build script includes all scaffolded days as modules of the `solutions` binary and collects
+/// these constants, which lets `all` and `time` run every day in a single process instead of one
+/// `cargo run` per day. The main binary does not include the days, so a day that does not compile
+/// only breaks the commands that run it.
+use std::{
+    any::Any,
+    env,
+    panic::{self, AssertUnwindSafe},
+    process::{Command, ExitStatus, Stdio},
+};
+
+use crate::template::{
+    protocol::PartRecord,
+    run_multi::child_commands::get_path_for_built_bin,
+    runner::{RunOptions, RunPartsFn},
+    Day, Error, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Name of the bin that all scaffolded days are compiled into.
+pub const SOLUTIONS_BIN: &str = "solutions";
+
+/// A solution that can be run in-process.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub day: Day,
+    pub run: RunPartsFn,
+}
+
+/// Why running a registered solution failed.
+#[derive(Debug)]
+pub enum Failure {
+    /// The solution panicked, with the panic message.
+    Panicked(String),
+    /// The solution returned an error, e.g. because its parameters are invalid.
+    Failed(Error),
+}
+
+/// Find the registered solution for a day.
+#[must_use]
+pub fn find(solutions: &[Solution], day: Day) -> Option<&Solution> {
+    solutions.iter().find(|s| s.day == day)
+}
+
+/// Run all parts of a registered solution against its puzzle input, appending to `records`.
+/// Panics inside the solution are caught and returned with their message. The records of
+/// parts that finished before a failure are kept.
+pub fn run_solution(
+    solution: &Solution,
+    input: &str,
+    options: &RunOptions,
+    records: &mut Vec<PartRecord>,
+) -> Result<(), Failure> {
+    catch_panic(|| (solution.run)(input, options, records))
+        .map_err(Failure::Panicked)?
+        .map_err(Failure::Failed)
+}
+
+/// Build the `solutions` binary and run the current command with it.
+/// Returns `None` if it could not be built, e.g. because a day does not compile, in which case
+/// the caller runs each day in its own process instead.
+pub fn run_solutions_bin() -> Option<ExitStatus> {
+    let is_release = !cfg!(debug_assertions);
+    let mut args = vec![
+        "build",
+        "--quiet",
+        "--features",
+        "registry",
+        "--bin",
+        SOLUTIONS_BIN,
+    ];
+    if is_release {
+        args.push("--release");
+    }
+
+    // the errors of a day that does not compile are shown when the day is built on its own.
+    let built = Command::new("cargo")
+        .args(&args)
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success());
+
+    if !built {
+        eprintln!(
+            "{ANSI_ITALIC}Could not build the solutions together, running each day in its own process.{ANSI_RESET}"
+        );
+        return None;
+    }
+
+    Command::new(get_path_for_built_bin(SOLUTIONS_BIN, is_release))
+        .args(env::args_os().skip(1))
+        .status()
+        .ok()
+}
+
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|e| panic_message(e.as_ref()))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{catch_panic, find, run_solution, Failure, Solution};
+    use crate::{
+        day,
+        template::{
+            protocol::{PartRecord, Status},
+            runner::RunOptions,
+            Error,
+        },
+    };
+
+    fn solutions() -> Vec<Solution> {
+        vec![
+            Solution {
+                day: day!(1),
+                run: |_, _, _| Ok(()),
+            },
+            Solution {
+                day: day!(6),
+                run: |_, _, _| panic!("megaloop1"),
+            },
+            Solution {
+                day: day!(7),
+                run: |_, _, records| {
+                    records.push(PartRecord {
+                        day: day!(7),
+                        part: 1,
+                        status: Status::Solved,
+                        answer: Some("42".into()),
+                        stats: Default::default(),
+                        heap: None,
+                    });
+                    panic!("part two is not done yet");
+                },
+            },
+            Solution {
+                day: day!(8),
+                run: |_, _, _| Err(Error::BadInput("unknown parameter `size`.".into())),
+            },
+        ]
+    }
+
+    #[test]
+    fn finds_registered_days() {
+        let solutions = solutions();
+        assert_eq!(find(&solutions, day!(6)).map(|s| s.day), Some(day!(6)));
+        assert!(find(&solutions, day!(2)).is_none());
+    }
+
+    #[test]
+    fn catches_panics() {
+        let solutions = solutions();
+        let solution = find(&solutions, day!(6)).unwrap();
+        let res = catch_panic(|| (solution.run)("", &RunOptions::default(), &mut vec![]));
+        assert_eq!(res.unwrap_err(), "megaloop1");
+    }
+
+    #[test]
+    fn keeps_records_of_parts_before_a_panic() {
+        let solutions = solutions();
+        let solution = find(&solutions, day!(7)).unwrap();
+
+        let mut records = vec![];
+        let res = run_solution(solution, "", &RunOptions::default(), &mut records);
+
+        assert!(res.is_err());
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].answer.as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn tells_errors_apart_from_panics() {
+        let solutions = solutions();
+        let run = |day| {
+            let solution = find(&solutions, day).unwrap();
+            run_solution(solution, "", &RunOptions::default(), &mut vec![])
+        };
+
+        assert!(run(day!(1)).is_ok());
+        assert!(matches!(run(day!(6)), Err(Failure::Panicked(message)) if message == "megaloop1"));
+        assert!(matches!(
+            run(day!(8)),
+            Err(Failure::Failed(Error::BadInput(_)))
+        ));
+    }
+
+    #[test]
+    fn catches_formatted_panics() {
+        let res: Result<(), String> = catch_panic(|| panic!("day {}", 24));
+        assert_eq!(res.unwrap_err(), "day 24");
+    }
+}
@@ -0,0 +1,486 @@
+/// Renders stored timings and accepted answers into standalone report files.
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    answers::Answers,
+    stats::{format_bytes, format_nanos},
+    timings::Timings,
+    Day,
+};
+
+/// Output format of a report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Json,
+    Html,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            "html" => Ok(Format::Html),
+            "md" | "markdown" => Ok(Format::Markdown),
+            x => Err(format!(
+                "unknown report format `{x}`, expected csv, json, html or md."
+            )),
+        }
+    }
+}
+
+/// What is known about a single part.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PartReport {
+    /// Mean duration in nanoseconds.
+    pub nanos: Option<f64>,
+    pub samples: Option<u128>,
+    /// Peak heap usage in bytes.
+    pub max_bytes: Option<u64>,
+    /// Accepted answer, see `cargo accept`.
+    pub answer: Option<String>,
+}
+
+/// What is known about a single day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayReport {
+    pub day: Day,
+    pub parts: [PartReport; 2],
+    /// The day was killed after exceeding its time limit when it was last benched.
+    pub timed_out: bool,
+}
+
+impl DayReport {
+    /// Status of the day, as shown in reports.
+    pub fn status(&self) -> &'static str {
+        if self.timed_out {
+            "timed out"
+        } else if self.parts.iter().all(|p| p.nanos.is_some()) {
+            "solved"
+        } else if self.parts.iter().any(|p| p.nanos.is_some()) {
+            "partial"
+        } else {
+            "not benched"
+        }
+    }
+
+    fn total_nanos(&self) -> f64 {
+        self.parts.iter().filter_map(|p| p.nanos).sum()
+    }
+}
+
+/// Collect everything known about each day that has a stored timing or an accepted answer.
+pub fn collect(timings: &Timings, answers: &Answers, year: &str) -> Vec<DayReport> {
+    let mut days: Vec<Day> = timings
+        .data
+        .iter()
+        .map(|t| t.day)
+        .chain(answers.days(year))
+        .collect();
+    days.sort_unstable();
+    days.dedup();
+
+    days.into_iter()
+        .map(|day| {
+            let timing = timings.get(day);
+            let part = |part: u8| {
+                let heap = timing.and_then(|t| match part {
+                    1 => t.part_1_heap.as_ref(),
+                    _ => t.part_2_heap.as_ref(),
+                });
+
+                PartReport {
+                    nanos: timing.and_then(|t| t.part_nanos(part)),
+                    samples: timing.and_then(|t| t.part_samples(part)),
+                    max_bytes: heap.map(|h| h.max_bytes),
+                    answer: answers.get(year, day, part).map(Into::into),
+                }
+            };
+
+            DayReport {
+                day,
+                parts: [part(1), part(2)],
+                timed_out: timing.is_some_and(|t| t.timed_out),
+            }
+        })
+        .collect()
+}
+
+/// Render a report in the given format.
+pub fn render(days: &[DayReport], format: Format) -> String {
+    match format {
+        Format::Csv => render_csv(days),
+        Format::Json => render_json(days),
+        Format::Html => render_html(days),
+        Format::Markdown => render_markdown(days),
+    }
+}
+
+fn or_empty<T: Display>(value: Option<T>) -> String {
+    value.map_or_else(String::new, |x| x.to_string())
+}
+
+/// One line per part, so the file can be loaded into a spreadsheet as is.
+fn render_csv(days: &[DayReport]) -> String {
+    let mut lines = vec!["day,part,status,nanos,samples,max_bytes,answer".to_string()];
+
+    for report in days {
+        for (part, p) in (1..).zip(&report.parts) {
+            lines.push(
+                [
+                    report.day.to_string(),
+                    part.to_string(),
+                    report.status().into(),
+                    or_empty(p.nanos),
+                    or_empty(p.samples),
+                    or_empty(p.max_bytes),
+                    csv_field(p.answer.as_deref().unwrap_or_default()),
+                ]
+                .join(","),
+            );
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.into()
+    }
+}
+
+fn render_json(days: &[DayReport]) -> String {
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+    map.insert(
+        "data".into(),
+        JsonValue::Array(days.iter().map(JsonValue::from).collect()),
+    );
+
+    // serializing a tree of plain values cannot fail.
+    JsonValue::Object(map).format().unwrap_or_default() + "\n"
+}
+
+fn render_markdown(days: &[DayReport]) -> String {
+    let format = |nanos: Option<f64>| nanos.map_or_else(|| "-".into(), format_nanos);
+    let answer = |answer: &Option<String>| {
+        answer
+            .as_deref()
+            .map_or_else(|| "-".into(), |a| format!("`{}`", a.replace('\n', " ")))
+    };
+
+    let mut lines: Vec<String> = vec![
+        "# Benchmarks".into(),
+        String::new(),
+        "| Day | Status | Part 1 | Part 2 | Answer 1 | Answer 2 |".into(),
+        "| :---: | :---: | :---: | :---: | :---: | :---: |".into(),
+    ];
+
+    for report in days {
+        let [part_1, part_2] = &report.parts;
+        lines.push(format!(
+            "| {} | {} | `{}` | `{}` | {} | {} |",
+            report.day.into_inner(),
+            report.status(),
+            format(part_1.nanos),
+            format(part_2.nanos),
+            answer(&part_1.answer),
+            answer(&part_2.answer),
+        ));
+    }
+
+    let total_millis = days.iter().map(DayReport::total_nanos).sum::<f64>() / 1_000_000_f64;
+    lines.push(String::new());
+    lines.push(format!("**Total: {total_millis:.2}ms**"));
+
+    lines.join("\n") + "\n"
+}
+
+/// Standalone page with a sortable table of all days and a bar chart per part.
+fn render_html(days: &[DayReport]) -> String {
+    let format = |nanos: Option<f64>| nanos.map_or_else(|| "-".into(), format_nanos);
+
+    let rows: String = days
+        .iter()
+        .map(|report| {
+            let [part_1, part_2] = &report.parts;
+            let cells = [
+                cell(report.day.into_inner(), &report.day.to_string()),
+                cell(report.status(), report.status()),
+                cell(or_empty(part_1.nanos), &format(part_1.nanos)),
+                cell(or_empty(part_2.nanos), &format(part_2.nanos)),
+                cell(
+                    or_empty(part_1.max_bytes),
+                    &part_1.max_bytes.map_or_else(|| "-".into(), format_bytes),
+                ),
+                cell(
+                    or_empty(part_2.max_bytes),
+                    &part_2.max_bytes.map_or_else(|| "-".into(), format_bytes),
+                ),
+                cell(
+                    part_1.answer.as_deref().unwrap_or_default(),
+                    part_1.answer.as_deref().unwrap_or("-"),
+                ),
+                cell(
+                    part_2.answer.as_deref().unwrap_or_default(),
+                    part_2.answer.as_deref().unwrap_or("-"),
+                ),
+            ];
+            format!("<tr>{}</tr>\n", cells.concat())
+        })
+        .collect();
+
+    let charts: String = [1, 2].iter().map(|part| bar_chart(days, *part)).collect();
+
+    HTML_TEMPLATE
+        .replace("{{ROWS}}", &rows)
+        .replace("{{CHARTS}}", &charts)
+}
+
+/// A table cell that sorts by `value` and displays `label`.
+fn cell(value: impl Display, label: &str) -> String {
+    format!(
+        "<td data-value=\"{}\">{}</td>",
+        escape_html(&value.to_string()),
+        escape_html(label)
+    )
+}
+
+/// Horizontal bars of the duration of one part per day, scaled to the slowest day.
+fn bar_chart(days: &[DayReport], part: u8) -> String {
+    let timed: Vec<(Day, f64)> = days
+        .iter()
+        .filter_map(|r| Some((r.day, r.parts[usize::from(part - 1)].nanos?)))
+        .collect();
+
+    let max = timed.iter().map(|(_, nanos)| *nanos).fold(0_f64, f64::max);
+
+    let bars: String = timed
+        .iter()
+        .map(|(day, nanos)| {
+            let width = if max > 0.0 { nanos / max * 100.0 } else { 0.0 };
+            format!(
+                "<div class=\"bar\"><span class=\"label\">Day {day}</span><span class=\"fill\" style=\"width: {width:.2}%\"></span><span class=\"value\">{}</span></div>\n",
+                format_nanos(*nanos)
+            )
+        })
+        .collect();
+
+    format!("<section class=\"chart\">\n<h2>Part {part}</h2>\n{bars}</section>\n")
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+static HTML_TEMPLATE: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Advent of Code Benchmarks</title>
+<style>
+body { font-family: sans-serif; margin: 2rem; color: #222; }
+table { border-collapse: collapse; }
+th, td { padding: 0.3rem 0.8rem; border-bottom: 1px solid #ddd; text-align: right; }
+th { cursor: pointer; user-select: none; background: #f4f4f4; }
+th.asc::after { content: " ▲"; }
+th.desc::after { content: " ▼"; }
+.chart { margin-top: 2rem; max-width: 50rem; }
+.bar { display: flex; align-items: center; margin: 0.2rem 0; }
+.bar .label { width: 5rem; }
+.bar .fill { height: 1rem; min-width: 1px; background: #4a7bd0; }
+.bar .value { margin-left: 0.5rem; font-size: 0.8rem; }
+</style>
+</head>
+<body>
+<h1>Benchmarks</h1>
+<table id="days">
+<thead>
+<tr><th data-type="number">Day</th><th>Status</th><th data-type="number">Part 1</th><th data-type="number">Part 2</th><th data-type="number">Part 1 heap</th><th data-type="number">Part 2 heap</th><th>Answer 1</th><th>Answer 2</th></tr>
+</thead>
+<tbody>
+{{ROWS}}</tbody>
+</table>
+{{CHARTS}}<script>
+document.querySelectorAll("#days th").forEach((th, column) => {
+  th.addEventListener("click", () => {
+    const ascending = !th.classList.contains("asc");
+    document.querySelectorAll("#days th").forEach((h) => h.classList.remove("asc", "desc"));
+    th.classList.add(ascending ? "asc" : "desc");
+
+    // parts without a timing sort as the slowest ones.
+    const numeric = th.dataset.type === "number";
+    const key = (row) => {
+      const value = row.children[column].dataset.value;
+      return numeric ? (value === "" ? Infinity : parseFloat(value)) : value;
+    };
+    const compare = (a, b) => {
+      const [x, y] = ascending ? [key(a), key(b)] : [key(b), key(a)];
+      return x < y ? -1 : x > y ? 1 : 0;
+    };
+
+    const body = document.querySelector("#days tbody");
+    const rows = [...body.rows].sort(compare);
+    rows.forEach((row) => body.appendChild(row));
+  });
+});
+</script>
+</body>
+</html>
+"##;
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&DayReport> for JsonValue {
+    fn from(value: &DayReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("status".into(), JsonValue::String(value.status().into()));
+
+        for (part, p) in (1..).zip(&value.parts) {
+            let mut part_map: HashMap<String, JsonValue> = HashMap::new();
+
+            part_map.insert(
+                "nanos".into(),
+                p.nanos.map_or(JsonValue::Null, JsonValue::Number),
+            );
+            #[allow(clippy::cast_precision_loss)]
+            part_map.insert(
+                "samples".into(),
+                p.samples
+                    .map_or(JsonValue::Null, |x| JsonValue::Number(x as f64)),
+            );
+            #[allow(clippy::cast_precision_loss)]
+            part_map.insert(
+                "max_bytes".into(),
+                p.max_bytes
+                    .map_or(JsonValue::Null, |x| JsonValue::Number(x as f64)),
+            );
+            part_map.insert(
+                "answer".into(),
+                p.answer.clone().map_or(JsonValue::Null, JsonValue::String),
+            );
+
+            map.insert(format!("part_{part}"), JsonValue::Object(part_map));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{collect, render, DayReport, Format, PartReport};
+    use crate::{
+        day,
+        template::{
+            answers::Answers,
+            stats::BenchStats,
+            timings::{Timing, Timings},
+        },
+    };
+    use tinyjson::JsonValue;
+
+    fn reports() -> Vec<DayReport> {
+        let mut answers = Answers::default();
+        answers.set("2024", day!(1), 1, "42".into());
+        answers.set("2024", day!(1), 2, "a,b".into());
+        answers.set("2024", day!(3), 1, "<7>".into());
+
+        let timings = Timings {
+            data: vec![
+                Timing {
+                    part_1_stats: Some(BenchStats {
+                        samples: 100,
+                        ..BenchStats::from_mean(74.0)
+                    }),
+                    part_2_stats: Some(BenchStats::from_mean(2_000_000.0)),
+                    ..Timing::empty(day!(1))
+                },
+                Timing {
+                    timed_out: true,
+                    ..Timing::empty(day!(2))
+                },
+            ],
+        };
+
+        collect(&timings, &answers, "2024")
+    }
+
+    #[test]
+    fn collects_timings_and_answers() {
+        let reports = reports();
+        assert_eq!(reports.len(), 3);
+        assert_eq!(reports[0].status(), "solved");
+        assert_eq!(reports[0].parts[1].answer, Some("a,b".into()));
+        assert_eq!(reports[1].status(), "timed out");
+        assert_eq!(reports[2].status(), "not benched");
+        assert_eq!(
+            reports[2].parts[0],
+            PartReport {
+                answer: Some("<7>".into()),
+                ..PartReport::default()
+            }
+        );
+    }
+
+    #[test]
+    fn renders_csv() {
+        let csv = render(&reports(), Format::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], "day,part,status,nanos,samples,max_bytes,answer");
+        assert_eq!(lines[1], "01,1,solved,74,100,,42");
+        assert_eq!(lines[2], "01,2,solved,2000000,,,\"a,b\"");
+        assert_eq!(lines[3], "02,1,timed out,,,,");
+    }
+
+    #[test]
+    fn renders_json() {
+        let json: JsonValue = render(&reports(), Format::Json).parse().unwrap();
+        let data: &Vec<JsonValue> = json["data"].get().unwrap();
+        assert_eq!(data.len(), 3);
+        assert_eq!(data[0]["part_1"]["nanos"], JsonValue::Number(74.0));
+        assert_eq!(data[0]["part_2"]["answer"], JsonValue::String("a,b".into()));
+        assert!(data[1]["part_1"]["nanos"].is_null());
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let md = render(&reports(), Format::Markdown);
+        assert!(md.contains("| 1 | solved | `74.0ns` | `2.0ms` | `42` | `a,b` |"));
+        assert!(md.contains("**Total: 2.00ms**"));
+    }
+
+    #[test]
+    fn renders_html() {
+        let html = render(&reports(), Format::Html);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<td data-value=\"74\">74.0ns</td>"));
+        assert!(html.contains("&lt;7&gt;"));
+        assert_eq!(html.matches("class=\"chart\"").count(), 2);
+        assert!(html.contains("style=\"width: 100.00%\""));
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("md".parse(), Ok(Format::Markdown));
+        assert_eq!("html".parse(), Ok(Format::Html));
+        assert!("pdf".parse::<Format>().is_err());
+    }
+}
@@ -1,48 +1,479 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    fmt::Display,
+    io,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
 
 use super::{
-    all_days,
+    all_days, input,
+    protocol::PartRecord,
+    registry::{self, Failure, Solution},
+    runner::RunOptions,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// How `run_multi` executes the solutions of each day.
+pub enum Mode<'a> {
+    /// Run registered solutions inside the current process.
+    InProcess(&'a [Solution]),
+    /// Run each day as a separate `cargo run` invocation.
+    Isolated {
+        is_release: bool,
+        timeouts: Timeouts,
+    },
+    /// Build all solutions once, then run up to `jobs` days at the same time.
+    /// Not suitable for timing, as concurrent days interfere with each other.
+    Parallel {
+        is_release: bool,
+        timeouts: Timeouts,
+        jobs: usize,
+    },
+}
 
-    let mut need_space = false;
+impl<'a> Mode<'a> {
+    /// Prefer running in-process, unless isolation was requested or no solutions are registered.
+    /// Days can only be killed when they run in isolation, so configured timeouts imply isolation.
+    pub fn select(
+        solutions: &'a [Solution],
+        isolated: bool,
+        is_release: bool,
+        timeouts: Timeouts,
+    ) -> Self {
+        if !isolated && !solutions.is_empty() && !timeouts.is_empty() {
+            eprintln!(
+                "{ANSI_ITALIC}A time limit is set, running each day in its own process.{ANSI_RESET}"
+            );
+        }
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+        if isolated || solutions.is_empty() || !timeouts.is_empty() {
+            Mode::Isolated {
+                is_release,
+                timeouts,
             }
-            need_space = true;
+        } else {
+            Mode::InProcess(solutions)
+        }
+    }
+}
+
+/// Environment variable that sets the default time limit per day, in seconds.
+pub const TIMEOUT_ENV: &str = "AOC_TIMEOUT";
+
+/// Wall-clock time limits for running a day, after which the day is killed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Timeouts {
+    pub default: Option<Duration>,
+    pub per_day: HashMap<Day, Duration>,
+}
+
+impl Timeouts {
+    /// Read the limits from `AOC_TIMEOUT` and per-day overrides like `AOC_TIMEOUT_17`.
+    /// A `default` passed on the command line takes precedence over `AOC_TIMEOUT`.
+    pub fn from_env(default: Option<Duration>) -> Result<Self, String> {
+        let mut timeouts = Timeouts::from_vars(env::vars())?;
+        if default.is_some() {
+            timeouts.default = default;
+        }
+        Ok(timeouts)
+    }
+
+    /// Build the limits from environment variables.
+    pub fn from_vars(vars: impl Iterator<Item = (String, String)>) -> Result<Self, String> {
+        let mut timeouts = Timeouts::default();
+
+        for (key, value) in vars {
+            let Some(suffix) = key.strip_prefix(TIMEOUT_ENV) else {
+                continue;
+            };
+
+            let seconds = parse_seconds(&value).ok_or(format!(
+                "expected {key} to be a number of seconds, got `{value}`."
+            ))?;
+
+            if suffix.is_empty() {
+                timeouts.default = Some(seconds);
+            } else if let Some(day) = suffix.strip_prefix('_') {
+                let day =
+                    Day::from_str(day).or(Err(format!("{key} does not name a valid day.")))?;
+                timeouts.per_day.insert(day, seconds);
+            }
+        }
+
+        Ok(timeouts)
+    }
+
+    /// Time limit of a day, if any.
+    pub fn for_day(&self, day: Day) -> Option<Duration> {
+        self.per_day.get(&day).copied().or(self.default)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none() && self.per_day.is_empty()
+    }
+}
+
+/// Parse a positive number of seconds, e.g. `30` or `2.5`.
+pub fn parse_seconds(value: &str) -> Option<Duration> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|s| s.is_finite() && *s > 0.0)
+        .map(Duration::from_secs_f64)
+}
+
+/// How the run of a single day ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayStatus {
+    Ok,
+    /// A part's answer differs from the accepted answer.
+    WrongAnswer,
+    Panicked,
+    /// The puzzle input is missing or damaged, e.g. empty because it was never downloaded.
+    BadInput,
+    BuildFailed,
+    NotScaffolded,
+    TimedOut,
+    /// The day could not be started, e.g. because its process failed to spawn.
+    CouldNotRun,
+}
+
+impl DayStatus {
+    /// Whether this status should fail the run.
+    pub fn is_failure(self) -> bool {
+        !matches!(self, DayStatus::Ok | DayStatus::NotScaffolded)
+    }
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    fn label(self) -> &'static str {
+        match self {
+            DayStatus::Ok => "ok",
+            DayStatus::WrongAnswer => "wrong answer",
+            DayStatus::Panicked => "panicked",
+            DayStatus::BadInput => "bad input",
+            DayStatus::BuildFailed => "build failed",
+            DayStatus::NotScaffolded => "not scaffolded",
+            DayStatus::TimedOut => "timed out",
+            DayStatus::CouldNotRun => "could not run",
+        }
+    }
+}
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+/// Result of running a single day.
+pub struct DayRun {
+    pub day: Day,
+    pub status: DayStatus,
+    /// Records of every part that ran, `None` if the day has not been scaffolded yet.
+    pub records: Option<Vec<PartRecord>>,
+}
 
-            if output.is_empty() {
-                println!("Not solved.");
+impl DayRun {
+    /// A day that failed to run for a reason outside of its solution.
+    fn could_not_run(day: Day) -> Self {
+        DayRun {
+            day,
+            status: DayStatus::CouldNotRun,
+            records: Some(vec![]),
+        }
+    }
+}
+
+/// Results of a `run_multi` invocation, in calendar order.
+pub struct MultiRun {
+    pub days: Vec<DayRun>,
+}
+
+impl MultiRun {
+    /// Timings of all scaffolded days.
+    pub fn timings(&self) -> Timings {
+        Timings {
+            data: self
+                .days
+                .iter()
+                .filter_map(|run| {
+                    let records = run.records.as_ref()?;
+                    let mut timing = child_commands::timing_from_records(records, run.day);
+                    timing.timed_out = run.status == DayStatus::TimedOut;
+                    Some(timing)
+                })
+                .collect(),
+        }
+    }
+
+    /// Records of all parts that ran.
+    pub fn records(&self) -> Vec<PartRecord> {
+        self.days
+            .iter()
+            .filter_map(|run| run.records.clone())
+            .flatten()
+            .collect()
+    }
+
+    /// Number of days whose run failed.
+    pub fn failures(&self) -> usize {
+        self.days
+            .iter()
+            .filter(|run| run.status.is_failure())
+            .count()
+    }
+
+    /// Print the status and answers of every scaffolded day.
+    pub fn print_summary(&self) {
+        println!("{ANSI_BOLD}Summary{ANSI_RESET}");
+        println!("{:<6}  {:<14}  {:<18}  Part 2", "Day", "Status", "Part 1");
+
+        for run in self
+            .days
+            .iter()
+            .filter(|r| r.status != DayStatus::NotScaffolded)
+        {
+            let answer = |part: u8| {
+                run.records
+                    .iter()
+                    .flatten()
+                    .find(|r| r.part == part)
+                    .and_then(|r| r.answer.clone())
+                    .unwrap_or_else(|| "-".into())
+            };
+
+            let (color, reset) = if run.status.is_failure() {
+                (ANSI_RED, ANSI_RESET)
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                ("", "")
+            };
+
+            println!(
+                "{color}{:<6}  {:<14}  {:<18}  {}{reset}",
+                format!("Day {}", run.day),
+                run.status.label(),
+                answer(1),
+                answer(2),
+            );
+        }
+
+        let not_scaffolded: Vec<String> = self
+            .days
+            .iter()
+            .filter(|r| r.status == DayStatus::NotScaffolded)
+            .map(|r| r.day.to_string())
+            .collect();
+
+        if !not_scaffolded.is_empty() {
+            println!(
+                "{ANSI_ITALIC}Not scaffolded: {}{ANSI_RESET}",
+                not_scaffolded.join(", ")
+            );
+        }
+    }
+}
+
+pub fn run_multi(days_to_run: &HashSet<Day>, mode: &Mode, options: &RunOptions) -> MultiRun {
+    // NOTE: use non-duplicate, sorted day values.
+    let days_to_run: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    let days = match mode {
+        Mode::Parallel {
+            is_release,
+            timeouts,
+            jobs,
+        } => {
+            let built =
+                child_commands::build_solutions(&days_to_run, *is_release).unwrap_or_else(|e| {
+                    eprintln!("{ANSI_RED}Failed to build solutions: {e}{ANSI_RESET}");
+                    false
+                });
+
+            if built {
+                run_parallel(&days_to_run, *is_release, timeouts, *jobs, options)
+            } else {
+                // the failing day can only be told apart when each day is built on its own.
+                println!("Building all solutions failed, running days one by one.\n");
+                let mode = Mode::Isolated {
+                    is_release: *is_release,
+                    timeouts: timeouts.clone(),
+                };
+                run_serial(&days_to_run, &mode, options)
             }
-        });
+        }
+        _ => run_serial(&days_to_run, mode, options),
+    };
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
+    let run = MultiRun { days };
+
+    if options.timed {
+        let total_millis = run.timings().total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+    }
+
+    run
+}
+
+/// Run days one after the other, forwarding their output as it is printed.
+fn run_serial(days_to_run: &[Day], mode: &Mode, options: &RunOptions) -> Vec<DayRun> {
+    days_to_run
+        .iter()
+        .enumerate()
+        .map(|(i, &day)| {
+            print_header(day, i > 0);
+            let run = run_day(day, mode, options).unwrap_or_else(|e| {
+                eprintln!("{ANSI_RED}Failed to run day {day}: {e}{ANSI_RESET}");
+                DayRun::could_not_run(day)
+            });
+            print_status(run.status);
+            run
+        })
+        .collect()
+}
+
+/// Run up to `jobs` prebuilt days at the same time.
+/// The output of each day is buffered and printed in calendar order once the day and all days before it finished.
+fn run_parallel(
+    days_to_run: &[Day],
+    is_release: bool,
+    timeouts: &Timeouts,
+    jobs: usize,
+    options: &RunOptions,
+) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days_to_run.len()) {
+            let sender = sender.clone();
+            let next = &next;
+
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days_to_run.get(i) else {
+                    break;
+                };
+
+                let result = child_commands::run_built_solution(
+                    day,
+                    options,
+                    is_release,
+                    timeouts.for_day(day),
+                )
+                .unwrap_or_else(|e| {
+                    let message = format!("{ANSI_RED}Failed to run day {day}: {e}{ANSI_RESET}");
+                    (
+                        DayRun::could_not_run(day),
+                        vec![child_commands::Line::Stderr(message)],
+                    )
+                });
+
+                if sender.send((i, result)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished: Vec<Option<(DayRun, Vec<child_commands::Line>)>> =
+            days_to_run.iter().map(|_| None).collect();
+        let mut days = Vec::with_capacity(days_to_run.len());
+
+        for (i, result) in receiver {
+            finished[i] = Some(result);
+
+            while let Some((run, output)) = finished.get_mut(days.len()).and_then(Option::take) {
+                print_header(run.day, !days.is_empty());
+                output.iter().for_each(child_commands::Line::print);
+                print_status(run.status);
+                days.push(run);
+            }
+        }
+
+        days
+    })
+}
+
+fn print_header(day: Day, need_space: bool) {
+    if need_space {
+        println!();
+    }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+fn print_status(status: DayStatus) {
+    match status {
+        DayStatus::NotScaffolded => println!("Not solved."),
+        DayStatus::BuildFailed => println!("{ANSI_RED}Build failed.{ANSI_RESET}"),
+        DayStatus::Panicked => println!("{ANSI_RED}Panicked.{ANSI_RESET}"),
+        // the problem and how to fix it, e.g. `cargo download`, have been printed when reading it.
+        DayStatus::BadInput => println!("{ANSI_RED}Bad input.{ANSI_RESET}"),
+        DayStatus::TimedOut => println!("{ANSI_RED}Timed out.{ANSI_RESET}"),
+        DayStatus::CouldNotRun => println!("{ANSI_RED}Could not run.{ANSI_RESET}"),
+        _ => {}
+    }
+}
+
+/// Run a single day and classify how it ended.
+fn run_day(day: Day, mode: &Mode, options: &RunOptions) -> Result<DayRun, Error> {
+    match mode {
+        Mode::InProcess(solutions) => {
+            let Some(solution) = registry::find(solutions, day) else {
+                return Ok(DayRun {
+                    day,
+                    status: DayStatus::NotScaffolded,
+                    records: None,
+                });
+            };
+
+            let input = match input::read("inputs", day, &format!("{day}.txt")) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return Ok(DayRun {
+                        day,
+                        status: DayStatus::BadInput,
+                        records: Some(vec![]),
+                    });
+                }
+            };
+
+            let mut records = vec![];
+            let status = match registry::run_solution(solution, &input, options, &mut records) {
+                Ok(()) => DayStatus::Ok,
+                // the panic message has already been printed by the panic hook.
+                Err(Failure::Panicked(_)) => DayStatus::Panicked,
+                Err(Failure::Failed(e)) => {
+                    eprintln!("{e}");
+                    match e {
+                        crate::template::Error::BadInput(_) => DayStatus::BadInput,
+                        _ => DayStatus::CouldNotRun,
+                    }
+                }
+            };
+
+            Ok(DayRun {
+                day,
+                status,
+                records: Some(records),
+            })
+        }
+        Mode::Isolated {
+            is_release,
+            timeouts,
+        }
+        | Mode::Parallel {
+            is_release,
+            timeouts,
+            ..
+        } => child_commands::run_solution(day, options, *is_release, timeouts.for_day(day)),
     }
 }
 
@@ -51,6 +482,17 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    Protocol(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => f.write_str("could not read the output of the solution"),
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Protocol(message) => write!(f, "invalid report: {message}"),
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -65,128 +507,288 @@ pub fn get_path_for_bin(day: Day) -> String {
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the records they report.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{get_path_for_bin, DayRun, DayStatus, Error};
+    use crate::template::{
+        input,
+        protocol::{self, PartRecord, Status, REPORT_FILE_ENV},
+        runner::RunOptions,
+        Day,
+    };
     use std::{
+        env, fs,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        path::{Path, PathBuf},
+        process::{self, Child, Command, Stdio},
+        sync::{Arc, Mutex},
         thread,
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given day and collect the part records it reported.
+    /// If the day exceeds `timeout`, it is killed and the parts that finished until then are kept.
+    pub fn run_solution(
+        day: Day,
+        options: &RunOptions,
+        is_release: bool,
+        timeout: Option<Duration>,
+    ) -> Result<DayRun, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            return Ok(DayRun {
+                day,
+                status: DayStatus::NotScaffolded,
+                records: None,
+            });
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        // run the built bin directly, so killing it on timeout does not leave the solution
+        // behind as an orphaned child of `cargo run`.
+        if timeout.is_some() {
+            if !build_solutions(&[day], is_release)? {
+                return Ok(DayRun {
+                    day,
+                    status: DayStatus::BuildFailed,
+                    records: Some(vec![]),
+                });
+            }
+
+            let mut command = Command::new(get_path_for_built_bin(&day.to_string(), is_release));
+            command.args(options.to_args());
+            return run_child(day, command, timeout, None);
+        }
+
+        let mut args: Vec<String> = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            day.to_string(),
+        ];
 
         if is_release {
-            args.push("--release");
+            args.push("--release".into());
+        }
+
+        // mirror options such as `--time` to child invocations.
+        args.push("--".into());
+        args.extend(options.to_args());
+
+        let mut command = Command::new("cargo");
+        command.args(&args);
+
+        run_child(day, command, timeout, None)
+    }
+
+    /// Build the solution bins of all scaffolded `days` with a single cargo invocation.
+    /// Returns whether the build succeeded.
+    pub fn build_solutions(days: &[Day], is_release: bool) -> Result<bool, Error> {
+        let mut args: Vec<String> = vec!["build".into(), "--quiet".into()];
+
+        for day in days {
+            if Path::new(&get_path_for_bin(*day)).exists() {
+                args.extend(["--bin".into(), day.to_string()]);
+            }
+        }
+
+        if args.len() == 2 {
+            return Ok(true);
+        }
+
+        if is_release {
+            args.push("--release".into());
+        }
+
+        Ok(Command::new("cargo").args(&args).status()?.success())
+    }
+
+    /// Run the prebuilt solution bin for a given day, see [`build_solutions`].
+    /// Its output is buffered and returned instead of being forwarded.
+    pub fn run_built_solution(
+        day: Day,
+        options: &RunOptions,
+        is_release: bool,
+        timeout: Option<Duration>,
+    ) -> Result<(DayRun, Vec<Line>), Error> {
+        if !Path::new(&get_path_for_bin(day)).exists() {
+            return Ok((
+                DayRun {
+                    day,
+                    status: DayStatus::NotScaffolded,
+                    records: None,
+                },
+                vec![],
+            ));
+        }
+
+        let mut command = Command::new(get_path_for_built_bin(&day.to_string(), is_release));
+        command.args(options.to_args());
+
+        let output = Arc::new(Mutex::new(vec![]));
+        let run = run_child(day, command, timeout, Some(Arc::clone(&output)))?;
+        let lines = output.lock().unwrap().drain(..).collect();
+
+        Ok((run, lines))
+    }
+
+    /// A line printed by a child process.
+    pub enum Line {
+        Stdout(String),
+        Stderr(String),
+    }
+
+    impl Line {
+        pub fn print(&self) {
+            match self {
+                Line::Stdout(line) => println!("{line}"),
+                Line::Stderr(line) => eprintln!("{line}"),
+            }
         }
+    }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+    /// Spawn a child that runs the solution of a day and collect the part records it reported.
+    /// Output lines are appended to `buffer` if one is given, and forwarded otherwise.
+    fn run_child(
+        day: Day,
+        mut command: Command,
+        timeout: Option<Duration>,
+        buffer: Option<Arc<Mutex<Vec<Line>>>>,
+    ) -> Result<DayRun, Error> {
+        let report_path = get_report_path(day);
+        if report_path.exists() {
+            fs::remove_file(&report_path)?;
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // spawn child command with piped stdout/stderr and forward its output.
+        // results are read from the report file, not from stdout.
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
+        command
+            .env(REPORT_FILE_ENV, &report_path)
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+            .stderr(Stdio::piped());
+
+        let mut cmd = command.spawn()?;
 
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let emit = move |line: Line| match &buffer {
+            Some(buffer) => buffer.lock().unwrap().push(line),
+            None => line.print(),
+        };
+        let emit_stdout = emit.clone();
+
+        // cargo reports compile errors on stderr, which is how build failures are told apart from panics.
+        let stderr_thread = thread::spawn(move || {
+            stderr
+                .lines()
+                .map_while(Result::ok)
+                .fold(false, |build_failed, line| {
+                    let build_failed = build_failed || is_build_failure(&line);
+                    emit(Line::Stderr(line));
+                    build_failed
+                })
+        });
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+        let stdout_thread = thread::spawn(move || {
+            stdout.lines().map_while(Result::ok).for_each(|line| {
+                emit_stdout(Line::Stdout(line));
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+        let exit_status = match timeout {
+            Some(timeout) => wait_with_timeout(&mut cmd, timeout)?,
+            None => Some(cmd.wait()?),
+        };
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        stdout_thread.join().unwrap();
+        let build_failed = stderr_thread.join().unwrap();
 
-        Ok(output)
-    }
+        let records = protocol::read_records(&report_path).map_err(Error::Protocol);
+        let _ = fs::remove_file(&report_path);
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+        let status = match exit_status {
+            Some(exit_status) => classify(exit_status.code(), build_failed),
+            None => DayStatus::TimedOut,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        Ok(DayRun {
+            day,
+            status,
+            records: Some(records?),
+        })
+    }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    /// Wait for the child to exit, killing it once `timeout` has passed.
+    /// Returns `None` if the child was killed.
+    fn wait_with_timeout(
+        cmd: &mut Child,
+        timeout: Duration,
+    ) -> Result<Option<process::ExitStatus>, Error> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            if let Some(exit_status) = cmd.try_wait()? {
+                return Ok(Some(exit_status));
+            }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            if Instant::now() >= deadline {
+                // the child is the solution bin itself, see `run_solution`.
+                let _ = cmd.kill();
+                cmd.wait()?;
+                return Ok(None);
+            }
 
-                timings.total_nanos += nanos;
-            });
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
 
-        timings
+    /// Whether a line of cargo's output reports a failed build.
+    pub fn is_build_failure(line: &str) -> bool {
+        line.starts_with("error: could not compile")
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Classify a finished child process by its exit code, `None` if it was terminated by a signal.
+    pub fn classify(code: Option<i32>, build_failed: bool) -> DayStatus {
+        match (code, build_failed) {
+            (Some(0), _) => DayStatus::Ok,
+            (_, true) => DayStatus::BuildFailed,
+            (Some(input::BAD_INPUT_EXIT_CODE), false) => DayStatus::BadInput,
+            _ => DayStatus::Panicked,
+        }
+    }
+
+    /// Path of the executable that `cargo build` produces for a bin, e.g. a day.
+    pub fn get_path_for_built_bin(name: &str, is_release: bool) -> PathBuf {
+        let target_dir =
+            env::var_os("CARGO_TARGET_DIR").map_or_else(|| "target".into(), PathBuf::from);
+        let profile = if is_release { "release" } else { "debug" };
+        target_dir
+            .join(profile)
+            .join(format!("{name}{}", env::consts::EXE_SUFFIX))
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+    fn get_report_path(day: Day) -> PathBuf {
+        env::temp_dir().join(format!("aoc-report-{}-{day}.jsonl", process::id()))
+    }
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+    /// Collect the timings of all solved parts reported for a day.
+    pub fn timing_from_records(records: &[PartRecord], day: Day) -> super::Timing {
+        let mut timings = super::Timing::empty(day);
 
-        Some((str_timing, parsed_timing))
+        records
+            .iter()
+            .filter(|r| r.day == day && r.status == Status::Solved)
+            .for_each(|r| {
+                timings.set_part(r.part, &r.stats);
+                match r.part {
+                    1 => timings.part_1_heap.clone_from(&r.heap),
+                    2 => timings.part_2_heap.clone_from(&r.heap),
+                    _ => {}
+                }
+            });
+
+        timings
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,53 +807,101 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{classify, is_build_failure, timing_from_records};
+        use crate::template::run_multi::{DayStatus, Timeouts};
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                input::BAD_INPUT_EXIT_CODE,
+                protocol::{PartRecord, Status},
+                stats::BenchStats,
+            },
+        };
 
-        use crate::day;
+        fn record(part: u8, status: Status, mean_nanos: f64) -> PartRecord {
+            PartRecord {
+                day: day!(1),
+                part,
+                status,
+                answer: (status == Status::Solved).then(|| "10".into()),
+                stats: BenchStats {
+                    samples: 100,
+                    mean: mean_nanos,
+                    ..BenchStats::default()
+                },
+                heap: None,
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_execution_times() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    record(1, Status::Solved, 74.0),
+                    record(2, Status::Solved, 74_130_000.0),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos(), 74130074_f64);
+            assert_approx_eq!(res.part_nanos(1).unwrap(), 74_f64);
+            assert_approx_eq!(res.part_nanos(2).unwrap(), 74130000_f64);
+            assert_eq!(res.part_samples(1), Some(100));
+            assert_eq!(res.part_1_stats.unwrap().samples, 100);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn reads_timeouts() {
+            let vars = [
+                ("AOC_TIMEOUT", "30"),
+                ("AOC_TIMEOUT_17", "120.5"),
+                ("AOC_YEAR", "2024"),
+            ]
+            .map(|(k, v)| (k.to_string(), v.to_string()));
+
+            let timeouts = Timeouts::from_vars(vars.into_iter()).unwrap();
+            assert_eq!(timeouts.for_day(day!(1)), Some(Duration::from_secs(30)));
+            assert_eq!(
+                timeouts.for_day(day!(17)),
+                Some(Duration::from_secs_f64(120.5))
+            );
+
+            let invalid = [("AOC_TIMEOUT_26".to_string(), "1".to_string())];
+            assert!(Timeouts::from_vars(invalid.into_iter()).is_err());
+            assert!(Timeouts::default().is_empty());
+        }
+
+        #[test]
+        fn classifies_runs() {
+            assert_eq!(classify(Some(0), false), DayStatus::Ok);
+            assert_eq!(classify(Some(101), false), DayStatus::Panicked);
+            assert_eq!(classify(None, false), DayStatus::Panicked);
+            assert_eq!(
+                classify(Some(BAD_INPUT_EXIT_CODE), false),
+                DayStatus::BadInput
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(classify(Some(101), true), DayStatus::BuildFailed);
+            assert!(is_build_failure(
+                "error: could not compile `advent_of_code` (bin \"06\") due to 1 previous error"
+            ));
+            assert!(!is_build_failure(
+                "thread 'main' panicked at src/bin/06.rs:12:5:"
+            ));
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn skips_unsolved_parts() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    record(1, Status::Unsolved, 10.0),
+                    record(2, Status::Unsolved, 10.0),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_approx_eq!(res.total_nanos(), 0_f64);
+            assert!(res.part_nanos(1).is_none());
+            assert!(res.part_nanos(2).is_none());
         }
     }
 }
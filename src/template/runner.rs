@@ -1,63 +1,498 @@
 /// Encapsulates code that interacts with solution functions.
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{stdin, stdout, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, io};
 
+use crate::template::answers::{current_year, print_verification, Answers, Check};
+use crate::template::params::{self, SolutionParams};
+use crate::template::protocol::{self, PartRecord, Status};
+use crate::template::stats::{format_nanos, BenchStats, HeapStats};
+use crate::template::submissions::{Outcome, Submission, Submissions};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_cli, input, Day, Error, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Settings for benchmarking a part with `--time`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchConfig {
+    /// Untimed iterations before sampling starts. Stops early once a tenth of the budget is used.
+    pub warmup: u32,
+    /// Approximate time to spend sampling a part.
+    pub budget: Duration,
+    /// Minimum number of samples, regardless of the budget.
+    pub min_samples: u128,
+    /// Maximum number of samples, regardless of the budget.
+    pub max_samples: u128,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            budget: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Parse the `--warmup`, `--budget`, `--min-samples` and `--max-samples` options.
+    /// `value` returns the raw value of an option, so that the `time` command and the solution
+    /// binaries share this parser.
+    pub fn parse<E: Display>(
+        mut value: impl FnMut(&'static str) -> Result<Option<String>, E>,
+    ) -> Result<Self, String> {
+        let mut number = |name: &'static str| -> Result<Option<u128>, String> {
+            match value(name).map_err(|e| e.to_string())? {
+                Some(v) => v
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| format!("{name} must be a non-negative integer, got `{v}`.")),
+                None => Ok(None),
+            }
+        };
+
+        let defaults = BenchConfig::default();
+        let config = BenchConfig {
+            warmup: match number("--warmup")? {
+                Some(warmup) => u32::try_from(warmup).map_err(|_| "--warmup is too large.")?,
+                None => defaults.warmup,
+            },
+            budget: match number("--budget")? {
+                Some(millis) => Duration::from_millis(
+                    u64::try_from(millis).map_err(|_| "--budget is too large.")?,
+                ),
+                None => defaults.budget,
+            },
+            min_samples: number("--min-samples")?.unwrap_or(defaults.min_samples),
+            max_samples: number("--max-samples")?.unwrap_or(defaults.max_samples),
+        };
+
+        if config.min_samples > config.max_samples {
+            return Err(format!(
+                "--min-samples ({}) must not be larger than --max-samples ({}).",
+                config.min_samples, config.max_samples
+            ));
+        }
+
+        Ok(config)
+    }
+
+    /// Arguments that reproduce this config when passed to a solution binary.
+    fn to_args(&self) -> Vec<String> {
+        let defaults = BenchConfig::default();
+        let mut args = vec![];
+
+        if self.warmup != defaults.warmup {
+            args.extend(["--warmup".into(), self.warmup.to_string()]);
+        }
+        if self.budget != defaults.budget {
+            args.extend(["--budget".into(), self.budget.as_millis().to_string()]);
+        }
+        if self.min_samples != defaults.min_samples {
+            args.extend(["--min-samples".into(), self.min_samples.to_string()]);
+        }
+        if self.max_samples != defaults.max_samples {
+            args.extend(["--max-samples".into(), self.max_samples.to_string()]);
+        }
+
+        args
+    }
+}
+
+/// Where a solution binary reads its input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/inputs`.
+    #[default]
+    Puzzle,
+    /// The example in `data/examples`.
+    Example,
+    /// An additional example in `data/examples`, e.g. `01-2.txt`.
+    ExamplePart(u8),
+    /// An arbitrary file.
+    File(PathBuf),
+    /// Standard input, selected with `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Build the input source from the `--input`, `--example` and `--example-part` options.
+    /// Returns an error if more than one of them is set.
+    pub fn from_options(
+        input: Option<String>,
+        example: bool,
+        example_part: Option<u8>,
+    ) -> Result<Self, String> {
+        match (input, example, example_part) {
+            (None, false, None) => Ok(InputSource::Puzzle),
+            (Some(path), false, None) if path == "-" => Ok(InputSource::Stdin),
+            (Some(path), false, None) => Ok(InputSource::File(path.into())),
+            (None, true, None) => Ok(InputSource::Example),
+            (None, false, Some(part)) => Ok(InputSource::ExamplePart(part)),
+            _ => Err("only one of --input, --example and --example-part can be used.".into()),
+        }
+    }
+
+    /// Read the input for a day. The error explains why it can't be read.
+    pub fn read(&self, day: Day) -> Result<String, Error> {
+        let res = match self {
+            InputSource::Puzzle => input::read("inputs", day, &format!("{day}.txt")),
+            InputSource::Example => input::read("examples", day, &format!("{day}.txt")),
+            InputSource::ExamplePart(part) => {
+                input::read("examples", day, &format!("{day}-{part}.txt"))
+            }
+            InputSource::File(path) => fs::read_to_string(path)
+                .map_err(|e| format!("could not read input file \"{}\": {e}", path.display()))
+                .and_then(|content| {
+                    check_fatal(content, &format!("input file \"{}\"", path.display()))
+                }),
+            InputSource::Stdin => io::read_to_string(stdin())
+                .map_err(|e| format!("could not read stdin: {e}"))
+                .and_then(|content| check_fatal(content, "input from stdin")),
+        };
+
+        res.map_err(Error::BadInput)
+    }
+
+    /// Path of the parameter sidecar file that belongs to this input, if there is one.
+    #[must_use]
+    pub fn params_path(&self, day: Day) -> Option<PathBuf> {
+        let input_path = match self {
+            InputSource::Puzzle => PathBuf::from(format!("data/inputs/{day}.txt")),
+            InputSource::Example => PathBuf::from(format!("data/examples/{day}.txt")),
+            InputSource::ExamplePart(part) => {
+                PathBuf::from(format!("data/examples/{day}-{part}.txt"))
+            }
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => return None,
+        };
+
+        Some(params::sidecar_path(&input_path))
+    }
+
+    /// Arguments that select this input source when passed to a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Example => vec!["--example".into()],
+            InputSource::ExamplePart(part) => vec!["--example-part".into(), part.to_string()],
+            InputSource::File(path) => vec!["--input".into(), path.display().to_string()],
+            InputSource::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+}
+
+/// Reject an input with a fatal problem, e.g. an empty one. `name` describes where it came from.
+fn check_fatal(content: String, name: &str) -> Result<String, String> {
+    match input::check(&content, false) {
+        Some(problem) if problem.is_fatal() => Err(format!("{name} {problem}.")),
+        _ => Ok(content),
+    }
+}
+
+/// Options that control how `run_part` executes a solution part.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Bench the part instead of running it once.
+    pub timed: bool,
+    /// Settings used when `timed` is set.
+    pub bench: BenchConfig,
+    /// Part whose result should be submitted via aoc-cli.
+    pub submit: Option<u8>,
+    /// Check the results against the accepted answers in `data/answers.json`.
+    pub verify: bool,
+    /// Input the solution binary runs against.
+    pub input: InputSource,
+    /// Only run this part, if set.
+    pub part: Option<u8>,
+    /// Parameter overrides passed with `--param name=value`.
+    pub params: params::Values,
+}
+
+impl RunOptions {
+    /// Parse the arguments passed to a solution binary by `solve`, `all` and `time`.
+    pub fn from_args() -> Result<Self, Error> {
+        let args: Vec<String> = env::args().collect();
+        let unexpected = |e: String| Error::Config(format!("Unexpected command-line input: {e}"));
+
+        let submit = arg_value(&args, "--submit", "cargo solve 1 --submit 1")?;
+
+        let bench = BenchConfig::parse(|name| raw_arg_value(&args, name)).map_err(unexpected)?;
+
+        let input = InputSource::from_options(
+            arg_value(&args, "--input", "cargo solve 1 --input path/to/input.txt")?,
+            args.iter().any(|x| x == "--example"),
+            arg_value(&args, "--example-part", "cargo solve 1 --example-part 2")?,
+        )
+        .map_err(unexpected)?;
+
+        let params = args
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| *x == "--param")
+            .map(|(i, _)| {
+                args.get(i + 1)
+                    .map_or(Err(String::new()), |v| params::parse_value(v))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|_| {
+                Error::Config(
+                    "Unexpected command-line input. Format: cargo solve 1 --param name=value"
+                        .into(),
+                )
+            })?;
+
+        Ok(RunOptions {
+            timed: args.iter().any(|x| x == "--time"),
+            bench,
+            submit,
+            verify: args.iter().any(|x| x == "--verify"),
+            input,
+            part: arg_value(&args, "--part", "cargo solve 1 --part 2")?,
+            params,
+        })
+    }
+
+    /// Whether `part` should run, i.e. no part or this part was selected.
+    #[must_use]
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// Arguments that reproduce these options when passed to a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if self.timed {
+            args.push("--time".into());
+            args.extend(self.bench.to_args());
+        }
+
+        if let Some(part) = self.submit {
+            args.extend(["--submit".into(), part.to_string()]);
+        }
+
+        if self.verify {
+            args.push("--verify".into());
+        }
+
+        args.extend(self.input.to_args());
+
+        if let Some(part) = self.part {
+            args.extend(["--part".into(), part.to_string()]);
+        }
+
+        for (name, value) in &self.params {
+            args.extend(["--param".into(), format!("{name}={value}")]);
+        }
+
+        args
+    }
+}
+
+/// Load the parameters of a solution from the sidecar file of its input and the `--param` overrides.
+/// Returns an error if a parameter is unknown or malformed.
+pub fn load_params<P: SolutionParams>(day: Day, options: &RunOptions) -> Result<P, Error> {
+    let sidecar = match options.input.params_path(day) {
+        Some(path) => params::read_values(&path),
+        None => Ok(vec![]),
+    };
+
+    sidecar
+        .map(|mut values| {
+            values.extend(options.params.iter().cloned());
+            values
+        })
+        .and_then(|values| P::from_values(&values))
+        .map_err(|e| Error::BadInput(format!("Invalid solution parameters: {e}")))
+}
+
+/// The value following `name`, if the option is set.
+fn raw_arg_value(args: &[String], name: &str) -> Result<Option<String>, String> {
+    match args.iter().position(|x| x == name) {
+        Some(index) => match args.get(index + 1) {
+            Some(value) => Ok(Some(value.clone())),
+            None => Err(format!("{name} requires a value.")),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Parse the value following `name`. The error contains a usage hint if it is malformed.
+fn arg_value<T: FromStr>(args: &[String], name: &str, usage: &str) -> Result<Option<T>, Error> {
+    let Some(index) = args.iter().position(|x| x == name) else {
+        return Ok(None);
+    };
+
+    match args.get(index + 1).map(|x| x.parse::<T>()) {
+        Some(Ok(value)) => Ok(Some(value)),
+        _ => Err(Error::Config(format!(
+            "Unexpected command-line input. Format: {usage}"
+        ))),
+    }
+}
+
+/// Signature of the `run_parts` function generated by the `solution!` macro.
+pub type RunPartsFn = fn(&str, &RunOptions, &mut Vec<PartRecord>) -> Result<(), Error>;
+
+/// Body of the `main` function generated by the `solution!` macro: parse the arguments, read the
+/// input and run the parts of the solution.
+pub fn run_bin(day: Day, run_parts: RunPartsFn) -> Result<(), Error> {
+    let options = RunOptions::from_args()?;
+    let input = options.input.read(day)?;
+
+    let mut records = vec![];
+    run_parts(&input, &options, &mut records)?;
+
+    if options.verify {
+        verify_records(&records)?;
+    }
+
+    Ok(())
+}
+
+/// Run a single part, print its result and report it.
+pub fn run_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> Result<PartRecord, Error> {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let bench = options.timed.then_some(&options.bench);
+    let (result, stats, heap) = run_timed(func, input, bench, |result| {
+        print_result(result, &part_str, "");
+    });
+
+    print_result(&result, &part_str, &format_duration(&stats));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    if result.is_some() && stats.samples > 1 {
+        println!("  {ANSI_ITALIC}{}{ANSI_RESET}", stats.summary());
+    }
+
+    if let Some(heap) = &heap {
+        println!("  {ANSI_ITALIC}heap: {}{ANSI_RESET}", heap.summary());
+    }
+
+    let record = PartRecord {
+        day,
+        part,
+        status: if result.is_some() {
+            Status::Solved
+        } else {
+            Status::Unsolved
+        },
+        answer: result.as_ref().map(ToString::to_string),
+        stats,
+        heap,
+    };
+
+    protocol::emit(&record);
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        if options.submit == Some(part) {
+            submit_result(result, day, part)?;
+        }
     }
+
+    Ok(record)
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Verify records against the accepted answers. Returns an error if any answer differs.
+pub fn verify_records(records: &[PartRecord]) -> Result<(), Error> {
+    let answers = Answers::read_from_file()?;
+    let verifications = answers.verify(&current_year(), records);
+
+    println!();
+    if print_verification(&verifications) {
+        return Ok(());
+    }
+
+    let wrong = verifications
+        .iter()
+        .filter(|v| matches!(v.check, Check::Fail { .. }))
+        .count();
+    Err(Error::WrongAnswers(wrong))
+}
+
+/// Run a solution part. The function is executed once, and benched afterwards if a bench config is passed.
+/// The heap usage of the first run is returned in builds with the `dhat-heap` feature.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    bench_config: Option<&BenchConfig>,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, BenchStats, Option<HeapStats>) {
     let timer = Instant::now();
-    let result = {
+    let (result, base_time, heap) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        let result = func(input);
+        let base_time = timer.elapsed();
+
+        // must be read before the profiler is dropped.
+        (result, base_time, heap_stats())
     };
-    let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    let stats = match bench_config {
+        Some(config) => bench(func, input, &base_time, config),
+        None => BenchStats::from_durations(&[base_time]),
     };
 
-    (result, run.0, run.1)
+    (result, stats, heap)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+#[cfg(feature = "dhat-heap")]
+fn heap_stats() -> Option<HeapStats> {
+    let stats = dhat::HeapStats::get();
+
+    Some(HeapStats {
+        total_bytes: stats.total_bytes,
+        total_blocks: stats.total_blocks,
+        max_bytes: stats.max_bytes as u64,
+    })
+}
+
+#[cfg(not(feature = "dhat-heap"))]
+fn heap_stats() -> Option<HeapStats> {
+    None
+}
+
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let warmup_timer = Instant::now();
+    for _ in 0..config.warmup {
+        if warmup_timer.elapsed() > config.budget / 10 {
+            break;
+        }
+        black_box(func(black_box(input)));
+    }
+
+    let bench_iterations = (config.budget.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(
+        config.min_samples,
+        cmp::max(config.max_samples, config.min_samples),
+    );
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,26 +502,16 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_durations(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+fn format_duration(stats: &BenchStats) -> String {
+    let duration = format_nanos(stats.mean);
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+    if stats.samples == 1 {
+        format!(" ({duration})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(" ({duration} @ {} samples)", stats.samples)
     }
 }
 
@@ -125,41 +550,148 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Try to submit one part of the solution if aoc-cli is installed.
+/// Answers that the submission history rules out are not submitted.
+/// A rejected answer is not an error, it is recorded in the submission history.
+fn submit_result<T: Display>(result: T, day: Day, part: u8) -> Result<(), Error> {
+    aoc_cli::check()?;
 
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
+    let answer = result.to_string();
+    let year = current_year();
+    // refuse to submit if the history can't be read, the submission could not be recorded.
+    let mut history = Submissions::read_from_file()?;
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if let Some(reason) = history.refusal(&year, day, part, &answer) {
+        println!("Not submitting {ANSI_BOLD}{answer}{ANSI_RESET}: {reason}");
+        return Ok(());
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    println!("Submitting result via aoc-cli...");
+    let res = aoc_cli::submit(day, part, &answer);
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let output = match res {
+        Ok(output) | Err(aoc_cli::AocCommandError::BadExitStatus(output)) => output,
+        Err(e) => return Err(e.into()),
     };
 
-    if part_submit != part {
-        return None;
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let outcome = Outcome::parse(&text);
+
+    history
+        .data
+        .push(Submission::new(&year, day, part, &answer, outcome));
+
+    match history.store_file() {
+        Ok(()) => println!("Recorded submission as {ANSI_ITALIC}{outcome}{ANSI_RESET}."),
+        Err(e) => eprintln!("Failed to record submission: {e}"),
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_fatal, raw_arg_value, BenchConfig, InputSource};
+    use std::time::Duration;
+
+    #[test]
+    fn selects_input_sources() {
+        assert_eq!(
+            InputSource::from_options(None, false, None),
+            Ok(InputSource::Puzzle)
+        );
+        assert_eq!(
+            InputSource::from_options(Some("-".into()), false, None),
+            Ok(InputSource::Stdin)
+        );
+        assert_eq!(
+            InputSource::from_options(Some("foo.txt".into()), false, None),
+            Ok(InputSource::File("foo.txt".into()))
+        );
+        assert_eq!(
+            InputSource::from_options(None, true, None),
+            Ok(InputSource::Example)
+        );
+        assert_eq!(
+            InputSource::from_options(None, false, Some(2)),
+            Ok(InputSource::ExamplePart(2))
+        );
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn rejects_conflicting_input_sources() {
+        assert!(InputSource::from_options(Some("-".into()), true, None).is_err());
+        assert!(InputSource::from_options(None, true, Some(2)).is_err());
+    }
+
+    #[test]
+    fn rejects_fatal_input_problems() {
+        assert_eq!(check_fatal("1 2\n".into(), "input"), Ok("1 2\n".into()));
+        // a missing final newline is not fatal, it is common when piping input.
+        assert_eq!(check_fatal("1 2".into(), "input"), Ok("1 2".into()));
+        assert_eq!(
+            check_fatal(" \n".into(), "input from stdin"),
+            Err("input from stdin is empty.".into())
+        );
+        assert!(check_fatal("1 2\r\n".into(), "input from stdin").is_err());
+    }
+
+    #[test]
+    fn mirrors_input_sources_as_args() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Example,
+            InputSource::ExamplePart(3),
+            InputSource::File("data/stress.txt".into()),
+            InputSource::Stdin,
+        ] {
+            let args = source.to_args();
+            let value = |name: &str| {
+                let index = args.iter().position(|x| x == name)?;
+                args.get(index + 1).cloned()
+            };
+            let parsed = InputSource::from_options(
+                value("--input"),
+                args.contains(&"--example".to_string()),
+                value("--example-part").map(|x| x.parse().unwrap()),
+            );
+            assert_eq!(parsed, Ok(source));
+        }
+    }
+
+    #[test]
+    fn mirrors_bench_config_as_args() {
+        let config = BenchConfig {
+            warmup: 5,
+            budget: Duration::from_millis(200),
+            min_samples: 50,
+            max_samples: 500,
+        };
+
+        let args = config.to_args();
+        assert_eq!(
+            BenchConfig::parse(|name| raw_arg_value(&args, name)),
+            Ok(config)
+        );
+        assert_eq!(
+            BenchConfig::parse(|name| raw_arg_value(&[], name)),
+            Ok(BenchConfig::default())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_bench_configs() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|x| x.to_string()).collect();
+            BenchConfig::parse(|name| raw_arg_value(&args, name))
+        };
+
+        assert!(parse(&["--warmup", "-1"]).is_err());
+        assert!(parse(&["--budget"]).is_err());
+        assert!(parse(&["--min-samples", "100", "--max-samples", "10"]).is_err());
+    }
 }
@@ -0,0 +1,236 @@
+/// Templates used by `cargo scaffold` to create solution modules.
+///
+/// Built-in templates live in `src/templates/`. Templates in a `templates/` directory at the root
+/// of the repository override built-ins of the same name and can add new ones, e.g.
+/// `templates/parse.txt` is used by `cargo scaffold 7 --template parse`.
+use std::{fs, path::Path};
+
+use crate::template::{aoc_cli, puzzle, Day};
+
+/// Directory that user-defined templates are read from.
+static USER_TEMPLATES_PATH: &str = "./templates";
+
+/// Template used when no `--template` is passed.
+pub const DEFAULT_TEMPLATE: &str = "blank";
+
+const BUILT_IN: &[(&str, &str)] = &[
+    (
+        "blank",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/blank.txt"
+        )),
+    ),
+    (
+        "graph",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/graph.txt"
+        )),
+    ),
+    (
+        "grid",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/grid.txt"
+        )),
+    ),
+    (
+        "nom",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/nom.txt"
+        )),
+    ),
+];
+
+/// Values that are filled into the placeholders of a template.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Context {
+    /// Puzzle title, e.g. `Day 1: Historian Hysteria`.
+    pub title: Option<String>,
+    pub year: Option<u16>,
+    /// Per part, the 1-based index of its example and the expected answer.
+    pub parts: Vec<(usize, Option<String>)>,
+}
+
+impl Context {
+    /// Read the context of a day from its downloaded puzzle description, if there is one.
+    pub fn read(day: Day) -> Self {
+        let markdown = fs::read_to_string(aoc_cli::get_puzzle_path(day)).unwrap_or_default();
+
+        Context {
+            title: puzzle::title(&markdown),
+            year: aoc_cli::get_year(),
+            parts: puzzle::examples(&puzzle::parse(&markdown)).parts,
+        }
+    }
+}
+
+/// Names of all available templates, sorted.
+pub fn names() -> Vec<String> {
+    let mut names: Vec<String> = BUILT_IN.iter().map(|(name, _)| name.to_string()).collect();
+
+    if let Ok(entries) = fs::read_dir(USER_TEMPLATES_PATH) {
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+    }
+
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Source of a template. User-defined templates take precedence over built-ins.
+pub fn load(name: &str) -> Result<String, String> {
+    let user_path = Path::new(USER_TEMPLATES_PATH).join(format!("{name}.txt"));
+
+    if let Ok(source) = fs::read_to_string(&user_path) {
+        return Ok(source);
+    }
+
+    BUILT_IN
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, source)| source.to_string())
+        .ok_or_else(|| {
+            format!(
+                "unknown template \"{name}\", available templates are: {}.",
+                names().join(", ")
+            )
+        })
+}
+
+/// Fill the placeholders of a template.
+///
+/// | Placeholder | Value |
+/// | --- | --- |
+/// | `%DAY_NUMBER%` | Day without padding, e.g. `7`. |
+/// | `%DAY%` | Day with padding, e.g. `07`. |
+/// | `%YEAR%` | Year from `AOC_YEAR`, empty if not set. |
+/// | `%TITLE%` | Puzzle title, `Day 7` if the puzzle was not downloaded. |
+/// | `%PART_ONE_EXAMPLE%`, `%PART_TWO_EXAMPLE%` | Call reading the example of a part. |
+/// | `%PART_ONE_EXPECTED%`, `%PART_TWO_EXPECTED%` | Expected example answer of a part, `None` if unknown or if it does not fit the return type of the part. |
+pub fn render(source: &str, day: Day, context: &Context) -> String {
+    let mut result = source
+        .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+        .replace("%DAY%", &day.to_string())
+        .replace(
+            "%YEAR%",
+            &context
+                .year
+                .map(|year| year.to_string())
+                .unwrap_or_default(),
+        )
+        .replace(
+            "%TITLE%",
+            &context
+                .title
+                .clone()
+                .unwrap_or_else(|| format!("Day {}", day.into_inner())),
+        );
+
+    for (i, name) in ["PART_ONE", "PART_TWO"].iter().enumerate() {
+        let (example, answer) = context.parts.get(i).cloned().unwrap_or((1, None));
+
+        let example = if example > 1 {
+            format!("read_file_part(\"examples\", DAY, {example})")
+        } else {
+            "read_file(\"examples\", DAY)".to_string()
+        };
+
+        let expected = answer
+            .zip(puzzle::answer_type(source, i as u8 + 1))
+            .and_then(|(answer, answer_type)| puzzle::expected_value(&answer, answer_type))
+            .unwrap_or_else(|| "None".into());
+
+        result = result
+            .replace(&format!("%{name}_EXAMPLE%"), &example)
+            .replace(&format!("%{name}_EXPECTED%"), &expected);
+    }
+
+    result
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{load, render, Context, BUILT_IN};
+    use crate::day;
+
+    #[test]
+    fn renders_defaults() {
+        let source = load("blank").unwrap();
+        let module = render(&source, day!(7), &Context::default());
+
+        assert!(module.starts_with("advent_of_code::solution!(7);"));
+        assert_eq!(module.matches("read_file(\"examples\", DAY)").count(), 2);
+        assert_eq!(module.matches("assert_eq!(result, None);").count(), 2);
+        assert!(!module.contains('%'));
+    }
+
+    #[test]
+    fn renders_context() {
+        let context = Context {
+            title: Some("Day 7: Bridge Repair".into()),
+            year: Some(2024),
+            parts: vec![(1, Some("3749".into())), (2, Some("a,b".into()))],
+        };
+
+        let module = render(
+            "fn part_one() -> Option<u64> {}\nfn part_two() -> Option<String> {}\n\
+            // %TITLE% (%YEAR%, day %DAY%)\n%PART_ONE_EXAMPLE% %PART_ONE_EXPECTED%\n%PART_TWO_EXAMPLE% %PART_TWO_EXPECTED%",
+            day!(7),
+            &context,
+        );
+
+        assert_eq!(
+            module,
+            "fn part_one() -> Option<u64> {}\nfn part_two() -> Option<String> {}\n\
+            // Day 7: Bridge Repair (2024, day 07)\n\
+            read_file(\"examples\", DAY) Some(3749)\n\
+            read_file_part(\"examples\", DAY, 2) Some(\"a,b\".to_string())"
+        );
+    }
+
+    #[test]
+    fn skips_answers_of_another_type() {
+        let context = Context {
+            parts: vec![(1, Some("4,6,3".into())), (1, Some("42".into()))],
+            ..Context::default()
+        };
+
+        for (name, source) in BUILT_IN {
+            let module = render(source, day!(1), &context);
+            assert!(
+                module.contains("assert_eq!(result, None);"),
+                "template {name} expects an answer of another type"
+            );
+            assert!(module.contains("assert_eq!(result, Some(42));"));
+        }
+    }
+
+    #[test]
+    fn renders_all_built_in_placeholders() {
+        for (name, source) in BUILT_IN {
+            let module = render(source, day!(1), &Context::default());
+            let has_placeholder = module
+                .split('%')
+                .skip(1)
+                .any(|s| s.starts_with(|c: char| c.is_ascii_uppercase()));
+            assert!(!has_placeholder, "template {name} has unknown placeholders");
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_templates() {
+        let err = load("does-not-exist").unwrap_err();
+        assert!(err.contains("blank, graph, grid, nom"));
+    }
+}
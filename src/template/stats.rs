@@ -0,0 +1,337 @@
+/// Summary statistics over benchmark samples and heap profiles.
+use std::{collections::HashMap, time::Duration};
+
+use tinyjson::JsonValue;
+
+/// Statistics of a benchmarked part. All durations are in nanoseconds.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub mean: f64,
+    pub min: f64,
+    pub median: f64,
+    pub p95: f64,
+    pub max: f64,
+    pub std_dev: f64,
+    /// Number of samples outside of the Tukey fences (1.5 IQR below Q1 or above Q3).
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Compute statistics from a set of measured durations.
+    #[must_use]
+    pub fn from_durations(durations: &[Duration]) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let mut nanos: Vec<f64> = durations.iter().map(|d| d.as_nanos() as f64).collect();
+
+        if nanos.is_empty() {
+            return BenchStats::default();
+        }
+
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / n;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+
+        let q1 = percentile(&nanos, 25.0);
+        let q3 = percentile(&nanos, 75.0);
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        BenchStats {
+            samples: nanos.len() as u128,
+            mean,
+            min: nanos[0],
+            median: percentile(&nanos, 50.0),
+            p95: percentile(&nanos, 95.0),
+            max: nanos[nanos.len() - 1],
+            std_dev: variance.sqrt(),
+            outliers: nanos.iter().filter(|&&x| x < low || x > high).count() as u128,
+        }
+    }
+
+    /// Statistics of a part that only the mean duration is known of, e.g. of an older timings file.
+    /// The number of samples is unknown and left at `0`.
+    #[must_use]
+    pub fn from_mean(mean: f64) -> Self {
+        BenchStats {
+            samples: 0,
+            mean,
+            min: mean,
+            median: mean,
+            p95: mean,
+            max: mean,
+            std_dev: 0.0,
+            outliers: 0,
+        }
+    }
+
+    /// Render the spread of the samples as a single line, e.g. for the terminal.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "min {} · median {} · p95 {} · max {} · σ {} · {} outlier{}",
+            format_nanos(self.min),
+            format_nanos(self.median),
+            format_nanos(self.p95),
+            format_nanos(self.max),
+            format_nanos(self.std_dev),
+            self.outliers,
+            if self.outliers == 1 { "" } else { "s" }
+        )
+    }
+}
+
+/// Heap usage of a single run of a part, as measured by `dhat`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Bytes allocated over the whole run.
+    pub total_bytes: u64,
+    /// Number of allocations over the whole run.
+    pub total_blocks: u64,
+    /// Bytes allocated at the moment heap usage peaked.
+    pub max_bytes: u64,
+}
+
+impl HeapStats {
+    /// Render the heap usage as a single line, e.g. for the terminal.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "{} total · {} peak · {} allocation{}",
+            format_bytes(self.total_bytes),
+            format_bytes(self.max_bytes),
+            self.total_blocks,
+            if self.total_blocks == 1 { "" } else { "s" }
+        )
+    }
+}
+
+/// Linearly interpolated percentile of sorted values.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - rank.floor())
+}
+
+/// Format nanoseconds the same way `Duration`'s debug output does.
+#[must_use]
+pub fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos.round() as u64);
+    format!("{duration:.1?}")
+}
+
+/// Format a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Parse a duration formatted by `format_nanos` back into nanoseconds.
+#[must_use]
+pub fn parse_nanos(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let s = s.trim();
+
+    match s {
+        s if s.ends_with("ns") => s.strip_suffix("ns")?.parse::<f64>().ok(),
+        s if s.ends_with("µs") => s
+            .strip_suffix("µs")?
+            .parse::<f64>()
+            .ok()
+            .map(|x| x * 1000_f64),
+        s if s.ends_with("ms") => s
+            .strip_suffix("ms")?
+            .parse::<f64>()
+            .ok()
+            .map(|x| x * 1_000_000_f64),
+        s => s
+            .strip_suffix('s')?
+            .parse::<f64>()
+            .ok()
+            .map(|x| x * 1_000_000_000_f64),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("mean".into(), JsonValue::Number(value.mean));
+        map.insert("min".into(), JsonValue::Number(value.min));
+        map.insert("median".into(), JsonValue::Number(value.median));
+        map.insert("p95".into(), JsonValue::Number(value.p95));
+        map.insert("max".into(), JsonValue::Number(value.max));
+        map.insert("std_dev".into(), JsonValue::Number(value.std_dev));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(BenchStats {
+            samples: number("samples")? as u128,
+            mean: number("mean")?,
+            min: number("min")?,
+            median: number("median")?,
+            p95: number("p95")?,
+            max: number("max")?,
+            std_dev: number("std_dev")?,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+impl From<&HeapStats> for JsonValue {
+    fn from(value: &HeapStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "total_bytes".into(),
+            JsonValue::Number(value.total_bytes as f64),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "total_blocks".into(),
+            JsonValue::Number(value.total_blocks as f64),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "max_bytes".into(),
+            JsonValue::Number(value.max_bytes as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HeapStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected heap to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected heap.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(HeapStats {
+            total_bytes: number("total_bytes")? as u64,
+            total_blocks: number("total_blocks")? as u64,
+            max_bytes: number("max_bytes")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_bytes, format_nanos, parse_nanos, BenchStats};
+
+    fn durations(nanos: &[u64]) -> Vec<Duration> {
+        nanos.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_stats() {
+        let stats = BenchStats::from_durations(&durations(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.mean, 3.0);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 3.0);
+        assert_eq!(stats.p95, 4.8);
+        assert_eq!(stats.max, 5.0);
+        assert_eq!(stats.std_dev, 2_f64.sqrt());
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn counts_outliers() {
+        let stats = BenchStats::from_durations(&durations(&[10, 10, 11, 11, 12, 12, 100]));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn handles_single_samples() {
+        let stats = BenchStats::from_durations(&durations(&[42]));
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.median, 42.0);
+        assert_eq!(stats.p95, 42.0);
+        assert_eq!(stats.std_dev, 0.0);
+    }
+
+    #[test]
+    fn handles_no_samples() {
+        assert_eq!(BenchStats::from_durations(&[]), BenchStats::default());
+    }
+
+    #[test]
+    fn formats_nanos() {
+        assert_eq!(format_nanos(74.0), "74.0ns");
+        assert_eq!(format_nanos(74_130_000.0), "74.1ms");
+    }
+
+    #[test]
+    fn parses_nanos() {
+        assert_eq!(parse_nanos("74.0ns"), Some(74.0));
+        assert_eq!(parse_nanos("2.5µs"), Some(2500.0));
+        assert_eq!(parse_nanos("74.1ms"), Some(74_100_000.0));
+        assert_eq!(parse_nanos("2s"), Some(2_000_000_000.0));
+        assert_eq!(parse_nanos("fast"), None);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(276), "276 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
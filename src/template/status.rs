@@ -0,0 +1,296 @@
+/// Overview of the progress of every day, as printed by `cargo status`.
+use std::{fmt::Write, fs, path::Path};
+
+use crate::template::{
+    puzzle::example_path,
+    submissions::{Outcome, Submissions},
+    timings::Timings,
+    Day, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET,
+};
+
+/// Number of days printed per row of the calendar.
+const DAYS_PER_ROW: usize = 5;
+
+/// Submission progress of a single part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartState {
+    Open,
+    /// All submitted answers were rejected.
+    Rejected,
+    /// A submitted answer was correct.
+    Solved,
+}
+
+impl PartState {
+    /// Derive the state of a part from the submission history.
+    pub fn read(submissions: &Submissions, year: &str, day: Day, part: u8) -> Self {
+        let outcomes: Vec<Outcome> = submissions
+            .for_part(year, day, part)
+            .map(|s| s.outcome)
+            .collect();
+
+        if outcomes.contains(&Outcome::Correct) {
+            PartState::Solved
+        } else if outcomes.iter().any(|o| o.is_rejection()) {
+            PartState::Rejected
+        } else {
+            PartState::Open
+        }
+    }
+
+    fn symbol(self) -> String {
+        match self {
+            PartState::Open => "·".into(),
+            PartState::Rejected => format!("{ANSI_RED}✖{ANSI_RESET}"),
+            PartState::Solved => format!("{ANSI_BOLD}★{ANSI_RESET}"),
+        }
+    }
+}
+
+/// Outcome of the example tests of a day.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TestState {
+    #[default]
+    NotRun,
+    Passed,
+    Failed,
+}
+
+/// Progress of a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgressStatus {
+    pub day: Day,
+    pub scaffolded: bool,
+    pub input: bool,
+    pub examples: bool,
+    pub tests: TestState,
+    /// Per part, whether a timing was stored in `data/timings.json`.
+    pub timed: [bool; 2],
+    pub parts: [PartState; 2],
+}
+
+impl ProgressStatus {
+    /// Inspect the module and data files of a day. Tests are not run, see [`TestState`].
+    pub fn read(day: Day, timings: &Timings, submissions: &Submissions, year: &str) -> Self {
+        let has_content = |path: &str| fs::read_to_string(path).is_ok_and(|s| !s.trim().is_empty());
+
+        ProgressStatus {
+            day,
+            scaffolded: Path::new(&format!("src/bin/{day}.rs")).exists(),
+            input: has_content(&format!("data/inputs/{day}.txt")),
+            examples: has_content(&example_path(day, 1)),
+            tests: TestState::NotRun,
+            timed: [1, 2].map(|part| {
+                timings
+                    .get(day)
+                    .is_some_and(|t| t.part_nanos(part).is_some())
+            }),
+            parts: [1, 2].map(|part| PartState::read(submissions, year, day, part)),
+        }
+    }
+
+    fn cell(&self) -> String {
+        let flag = |set: bool, c: char| if set { c } else { '·' };
+
+        let tests = match self.tests {
+            TestState::NotRun => "·".into(),
+            TestState::Passed => format!("{ANSI_GREEN}T{ANSI_RESET}"),
+            TestState::Failed => format!("{ANSI_RED}F{ANSI_RESET}"),
+        };
+
+        format!(
+            "{} {}{}{}{tests} {}{} {}{}",
+            self.day,
+            flag(self.scaffolded, 'S'),
+            flag(self.input, 'I'),
+            flag(self.examples, 'E'),
+            flag(self.timed[0], '◆'),
+            flag(self.timed[1], '◆'),
+            self.parts[0].symbol(),
+            self.parts[1].symbol(),
+        )
+    }
+}
+
+/// Counts across all days.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub scaffolded: usize,
+    pub inputs: usize,
+    pub examples: usize,
+    pub tests_passed: usize,
+    pub tests_failed: usize,
+    pub timed: usize,
+    pub stars: usize,
+}
+
+impl Totals {
+    pub fn new(days: &[ProgressStatus]) -> Self {
+        let count = |f: &dyn Fn(&ProgressStatus) -> bool| days.iter().filter(|d| f(d)).count();
+
+        Totals {
+            scaffolded: count(&|d| d.scaffolded),
+            inputs: count(&|d| d.input),
+            examples: count(&|d| d.examples),
+            tests_passed: count(&|d| d.tests == TestState::Passed),
+            tests_failed: count(&|d| d.tests == TestState::Failed),
+            timed: days.iter().flat_map(|d| d.timed).filter(|t| *t).count(),
+            stars: days
+                .iter()
+                .flat_map(|d| d.parts)
+                .filter(|p| *p == PartState::Solved)
+                .count(),
+        }
+    }
+}
+
+/// Render the calendar, a legend and the totals.
+pub fn render(days: &[ProgressStatus]) -> String {
+    let mut output = String::new();
+
+    for row in days.chunks(DAYS_PER_ROW) {
+        let cells: Vec<String> = row.iter().map(ProgressStatus::cell).collect();
+        writeln!(output, "{}", cells.join("   ")).unwrap();
+    }
+
+    let totals = Totals::new(days);
+    let all = days.len();
+
+    let tests = if totals.tests_passed + totals.tests_failed == 0 {
+        "not run".into()
+    } else {
+        format!(
+            "{} passed, {} failed",
+            totals.tests_passed, totals.tests_failed
+        )
+    };
+
+    writeln!(output).unwrap();
+    writeln!(
+        output,
+        "S scaffolded · I input · E examples · T/F tests passed/failed · ◆ timed part · ✖ rejected · ★ solved"
+    )
+    .unwrap();
+    writeln!(output).unwrap();
+    writeln!(
+        output,
+        "{ANSI_BOLD}Scaffolded:{ANSI_RESET} {}/{all} · {ANSI_BOLD}Inputs:{ANSI_RESET} {}/{all} · {ANSI_BOLD}Examples:{ANSI_RESET} {}/{all} · {ANSI_BOLD}Tests:{ANSI_RESET} {tests}",
+        totals.scaffolded, totals.inputs, totals.examples,
+    )
+    .unwrap();
+    writeln!(
+        output,
+        "{ANSI_BOLD}Timed:{ANSI_RESET} {}/{} parts · {ANSI_BOLD}Stars:{ANSI_RESET} {}/{}",
+        totals.timed,
+        all * 2,
+        totals.stars,
+        all * 2,
+    )
+    .unwrap();
+
+    output
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, PartState, ProgressStatus, TestState, Totals};
+    use crate::{
+        day,
+        template::{
+            submissions::{Outcome, Submission, Submissions},
+            Day,
+        },
+    };
+
+    fn status(
+        day: Day,
+        tests: TestState,
+        timed: [bool; 2],
+        parts: [PartState; 2],
+    ) -> ProgressStatus {
+        ProgressStatus {
+            day,
+            scaffolded: true,
+            input: true,
+            examples: false,
+            tests,
+            timed,
+            parts,
+        }
+    }
+
+    #[test]
+    fn derives_part_states() {
+        let submissions = Submissions {
+            data: vec![
+                Submission::new("2024", day!(1), 1, "10", Outcome::TooLow),
+                Submission::new("2024", day!(1), 1, "42", Outcome::Correct),
+                Submission::new("2024", day!(1), 2, "7", Outcome::Wrong),
+                Submission::new("2024", day!(2), 1, "7", Outcome::RateLimited),
+            ],
+        };
+
+        let state = |day, part| PartState::read(&submissions, "2024", day, part);
+
+        assert_eq!(state(day!(1), 1), PartState::Solved);
+        assert_eq!(state(day!(1), 2), PartState::Rejected);
+        assert_eq!(state(day!(2), 1), PartState::Open);
+        assert_eq!(
+            PartState::read(&submissions, "2023", day!(1), 1),
+            PartState::Open
+        );
+    }
+
+    #[test]
+    fn counts_totals() {
+        let days = vec![
+            status(
+                day!(1),
+                TestState::Passed,
+                [true; 2],
+                [PartState::Solved; 2],
+            ),
+            status(
+                day!(2),
+                TestState::Failed,
+                [true, false],
+                [PartState::Open; 2],
+            ),
+            status(
+                day!(3),
+                TestState::NotRun,
+                [false; 2],
+                [PartState::Rejected, PartState::Open],
+            ),
+        ];
+
+        assert_eq!(
+            Totals::new(&days),
+            Totals {
+                scaffolded: 3,
+                inputs: 3,
+                examples: 0,
+                tests_passed: 1,
+                tests_failed: 1,
+                timed: 3,
+                stars: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn renders_calendar() {
+        let days: Vec<ProgressStatus> = crate::template::all_days()
+            .map(|day| status(day, TestState::NotRun, [false; 2], [PartState::Open; 2]))
+            .collect();
+
+        let output = render(&days);
+        let rows: Vec<&str> = output.lines().take_while(|l| !l.is_empty()).collect();
+
+        assert_eq!(rows.len(), 5);
+        assert!(rows[0].starts_with("01 SI·· ·· ··   02 SI·· ·· ··"));
+        assert!(output.contains("Tests:\u{1b}[0m not run"));
+    }
+}
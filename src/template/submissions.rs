@@ -0,0 +1,379 @@
+/// Local history of submitted answers, used to avoid resubmitting answers that are known to be wrong.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{self, ErrorKind},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Error};
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.json";
+
+/// Outcome of a submission, as reported by aoc-cli.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    /// The output of aoc-cli could not be interpreted.
+    Unknown,
+}
+
+impl Outcome {
+    /// Interpret the output of `aoc submit`.
+    pub fn parse(output: &str) -> Self {
+        let output = output.to_lowercase();
+
+        if output.contains("that's the right answer") {
+            Outcome::Correct
+        } else if output.contains("answer too recently") || output.contains("left to wait") {
+            Outcome::RateLimited
+        } else if output.contains("too high") {
+            Outcome::TooHigh
+        } else if output.contains("too low") {
+            Outcome::TooLow
+        } else if output.contains("not the right answer") {
+            Outcome::Wrong
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    /// Whether the answer was rejected by the server.
+    pub fn is_rejection(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited => "rate_limited",
+            Outcome::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.as_str().replace('_', " "))
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Outcome::Correct,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::Wrong,
+            Outcome::RateLimited,
+            Outcome::Unknown,
+        ]
+        .into_iter()
+        .find(|o| o.as_str() == s)
+        .ok_or(format!("unknown submission outcome `{s}`."))
+    }
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub year: String,
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+impl Submission {
+    pub fn new(year: &str, day: Day, part: u8, answer: &str, outcome: Outcome) -> Self {
+        Submission {
+            year: year.into(),
+            day,
+            part,
+            answer: answer.into(),
+            outcome,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        }
+    }
+}
+
+/// Append-only history of submissions.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(SUBMISSIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns an empty history.
+    /// A file that cannot be read or parsed is an error, so that it does not get overwritten.
+    pub fn read_from_file() -> Result<Self, Error> {
+        match fs::read_to_string(SUBMISSIONS_FILE_PATH) {
+            Ok(content) => Submissions::try_from(content).map_err(|message| Error::DataFile {
+                path: SUBMISSIONS_FILE_PATH.into(),
+                message,
+            }),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(Error::io("read", SUBMISSIONS_FILE_PATH)(e)),
+        }
+    }
+
+    /// Submissions of a single part.
+    pub fn for_part<'a>(
+        &'a self,
+        year: &'a str,
+        day: Day,
+        part: u8,
+    ) -> impl Iterator<Item = &'a Submission> {
+        self.data
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part)
+    }
+
+    /// Explains why `answer` should not be submitted, if the history rules it out.
+    pub fn refusal(&self, year: &str, day: Day, part: u8, answer: &str) -> Option<String> {
+        if let Some(correct) = self
+            .for_part(year, day, part)
+            .find(|s| s.outcome == Outcome::Correct)
+        {
+            return Some(format!(
+                "this part was already solved with answer {}.",
+                correct.answer
+            ));
+        }
+
+        if let Some(rejected) = self
+            .for_part(year, day, part)
+            .find(|s| s.answer == answer && s.outcome.is_rejection())
+        {
+            return Some(format!(
+                "this answer was already rejected ({}).",
+                rejected.outcome
+            ));
+        }
+
+        let value = answer.trim().parse::<i128>().ok()?;
+        let numeric = |outcome: Outcome| {
+            self.for_part(year, day, part)
+                .filter(move |s| s.outcome == outcome)
+                .filter_map(|s| s.answer.trim().parse::<i128>().ok())
+        };
+
+        if let Some(upper) = numeric(Outcome::TooHigh).min() {
+            if value >= upper {
+                return Some(format!(
+                    "answers of {upper} or more are known to be too high."
+                ));
+            }
+        }
+
+        if let Some(lower) = numeric(Outcome::TooLow).max() {
+            if value <= lower {
+                return Some(format!(
+                    "answers of {lower} or less are known to be too low."
+                ));
+            }
+        }
+
+        None
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::String(value.year.clone()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(value.outcome.as_str().into()),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("Expected submission.{key} to be a string."))
+        };
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected submission.{key} to be a number."))
+        };
+
+        let day = Day::from_str(string("day")?).or(Err("Expected submission.day to be a Day."))?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Submission {
+            year: string("year")?.clone(),
+            day,
+            part: number("part")? as u8,
+            answer: string("answer")?.clone(),
+            outcome: string("outcome")?.parse()?,
+            timestamp: number("timestamp")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Outcome, Submission, Submissions};
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    fn history(entries: &[(&str, Outcome)]) -> Submissions {
+        Submissions {
+            data: entries
+                .iter()
+                .map(|(answer, outcome)| Submission::new("2024", day!(1), 1, answer, *outcome))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(
+            Outcome::parse("That's the right answer! You are one gold star closer."),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too high."),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer. If you're stuck, ..."),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently. You have 45s left to wait."),
+            Outcome::RateLimited
+        );
+        assert_eq!(Outcome::parse("error: network"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn refuses_rejected_answers() {
+        let history = history(&[("abc", Outcome::Wrong)]);
+        assert!(history.refusal("2024", day!(1), 1, "abc").is_some());
+        assert!(history.refusal("2024", day!(1), 1, "abd").is_none());
+        assert!(history.refusal("2024", day!(1), 2, "abc").is_none());
+        assert!(history.refusal("2023", day!(1), 1, "abc").is_none());
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let history = history(&[
+            ("100", Outcome::TooHigh),
+            ("200", Outcome::TooHigh),
+            ("10", Outcome::TooLow),
+            ("50", Outcome::RateLimited),
+        ]);
+        assert!(history.refusal("2024", day!(1), 1, "100").is_some());
+        assert!(history.refusal("2024", day!(1), 1, "150").is_some());
+        assert!(history.refusal("2024", day!(1), 1, "5").is_some());
+        assert!(history.refusal("2024", day!(1), 1, "50").is_none());
+        assert!(history.refusal("2024", day!(1), 1, "99").is_none());
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let history = history(&[("42", Outcome::Correct)]);
+        assert!(history.refusal("2024", day!(1), 1, "43").is_some());
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let history = history(&[("100", Outcome::TooHigh), ("4,6,3", Outcome::Correct)]);
+        let json = JsonValue::from(history.clone()).stringify().unwrap();
+        assert_eq!(Submissions::try_from(json).unwrap(), history);
+    }
+}
@@ -1,17 +1,106 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, ErrorKind},
+    str::FromStr,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{
+    stats::{parse_nanos, BenchStats, HeapStats},
+    Day, Error,
+};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+pub static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+
+/// Version of the timings file schema. Bumped on breaking changes.
+/// Version 1 stored formatted durations like `"74.1ns"` and had no `version` key.
+pub const TIMINGS_VERSION: u32 = 2;
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    /// Benchmark statistics of the parts, holding their mean duration and number of samples.
+    /// Timings of older files without stats only know the mean, see [`BenchStats::from_mean`].
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Heap usage of the parts, recorded by `cargo solve --dhat`.
+    pub part_1_heap: Option<HeapStats>,
+    pub part_2_heap: Option<HeapStats>,
+    /// The day was killed after exceeding its time limit.
+    pub timed_out: bool,
+}
+
+impl Timing {
+    /// A timing without any benched parts.
+    pub fn empty(day: Day) -> Self {
+        Timing {
+            day,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_heap: None,
+            part_2_heap: None,
+            timed_out: false,
+        }
+    }
+
+    /// Keep the heap usage of `stored` for parts that `self` has none for.
+    /// Heap usage is only measured by `cargo solve --dhat`, so benchmark runs must not discard it.
+    fn keep_heap(&mut self, stored: &Timing) {
+        if self.part_1_heap.is_none() {
+            self.part_1_heap.clone_from(&stored.part_1_heap);
+        }
+        if self.part_2_heap.is_none() {
+            self.part_2_heap.clone_from(&stored.part_2_heap);
+        }
+    }
+
+    /// Replace one part with the timing of `other`.
+    fn replace_part(&mut self, other: &Timing, part: u8) {
+        match part {
+            1 => self.part_1_stats.clone_from(&other.part_1_stats),
+            2 => self.part_2_stats.clone_from(&other.part_2_stats),
+            _ => return,
+        }
+
+        self.timed_out = other.timed_out;
+    }
+
+    /// Set the timing of a part from its benchmark statistics.
+    pub fn set_part(&mut self, part: u8, stats: &BenchStats) {
+        match part {
+            1 => self.part_1_stats = Some(stats.clone()),
+            2 => self.part_2_stats = Some(stats.clone()),
+            _ => {}
+        }
+    }
+
+    /// Benchmark statistics of a part.
+    pub fn part_stats(&self, part: u8) -> Option<&BenchStats> {
+        match part {
+            1 => self.part_1_stats.as_ref(),
+            2 => self.part_2_stats.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Mean duration of a part in nanoseconds.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        self.part_stats(part).map(|stats| stats.mean)
+    }
+
+    /// Number of samples the mean of a part was computed over, if known.
+    pub fn part_samples(&self, part: u8) -> Option<u128> {
+        self.part_stats(part)
+            .map(|stats| stats.samples)
+            .filter(|samples| *samples > 0)
+    }
+
+    /// Sum of the mean durations of all benched parts in nanoseconds.
+    pub fn total_nanos(&self) -> f64 {
+        [1, 2].iter().filter_map(|p| self.part_nanos(*p)).sum()
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -23,18 +112,23 @@ pub struct Timings {
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self) -> Result<(), io::Error> {
         let json = JsonValue::from(self.clone());
         let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+    /// A file that cannot be read or parsed, e.g. of an unsupported version, is an error.
+    pub fn read_from_file() -> Result<Self, Error> {
+        match fs::read_to_string(TIMINGS_FILE_PATH) {
+            Ok(content) => Timings::try_from(content).map_err(|message| Error::DataFile {
+                path: TIMINGS_FILE_PATH.into(),
+                message,
+            }),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Timings::default()),
+            Err(e) => Err(Error::io("read", TIMINGS_FILE_PATH)(e)),
+        }
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
@@ -42,7 +136,11 @@ impl Timings {
         let mut data: Vec<Timing> = vec![];
 
         for timing in &new.data {
-            data.push(timing.clone());
+            let mut timing = timing.clone();
+            if let Some(stored) = self.get(timing.day) {
+                timing.keep_heap(stored);
+            }
+            data.push(timing);
         }
 
         for timing in &self.data {
@@ -51,19 +149,80 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
+    /// Merge the timings of a single part, leaving the other part of `self` untouched.
+    pub fn merge_part(&self, new: &Self, part: u8) -> Self {
+        let mut merged = self.clone();
+
+        for timing in &new.data {
+            match merged.data.iter_mut().find(|t| t.day == timing.day) {
+                Some(stored) => stored.replace_part(timing, part),
+                None => {
+                    let mut stored = Timing::empty(timing.day);
+                    stored.replace_part(timing, part);
+                    merged.data.push(stored);
+                }
+            }
+        }
+
+        merged.data.sort_unstable_by_key(|a| a.day);
+        merged
+    }
+
+    /// Merge the heap usage of `new`, leaving the timings of `self` untouched.
+    pub fn merge_heap(&self, new: &Self) -> Self {
+        let mut merged = self.clone();
+
+        for timing in &new.data {
+            let mut heap = timing.clone();
+
+            match merged.data.iter_mut().find(|t| t.day == timing.day) {
+                Some(stored) => {
+                    heap.keep_heap(stored);
+                    stored.part_1_heap = heap.part_1_heap;
+                    stored.part_2_heap = heap.part_2_heap;
+                }
+                None => {
+                    let mut stored = Timing::empty(timing.day);
+                    stored.part_1_heap = heap.part_1_heap;
+                    stored.part_2_heap = heap.part_2_heap;
+                    merged.data.push(stored);
+                }
+            }
+        }
+
+        merged.data.sort_unstable_by_key(|a| a.day);
+        merged
+    }
+
+    /// Whether any timing has heap usage recorded.
+    pub fn has_heap(&self) -> bool {
+        self.data
+            .iter()
+            .any(|t| t.part_1_heap.is_some() || t.part_2_heap.is_some())
+    }
+
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        self.data.iter().map(Timing::total_nanos).sum::<f64>() / 1_000_000_f64
+    }
+
+    /// Find the timing of a single day.
+    pub fn get(&self, day: Day) -> Option<&Timing> {
+        self.data.iter().find(|t| t.day == day)
+    }
+
+    pub fn is_part_complete(&self, day: Day, part: u8) -> bool {
+        self.get(day).is_some_and(|t| t.part_nanos(part).is_some())
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && t.part_1_stats.is_some() && t.part_2_stats.is_some())
     }
 }
 
@@ -73,6 +232,10 @@ impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(TIMINGS_VERSION)),
+        );
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -88,19 +251,35 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        // files written before the schema was versioned have no `version` key.
+        let version = match json.get("version") {
+            Some(v) => v
+                .get::<f64>()
+                .copied()
+                .ok_or("expected `json.version` to be a number.")?,
+            None => 1.0,
+        };
+
+        let parse: fn(&JsonValue) -> Result<Timing, String> = if version == 1.0 {
+            Timing::try_from_v1
+        } else if version == f64::from(TIMINGS_VERSION) {
+            |v| Timing::try_from(v)
+        } else {
+            return Err(format!("unsupported timings version {version}."));
+        };
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
         Ok(Timings {
-            data: json_data
-                .iter()
-                .map(Timing::try_from)
-                .collect::<Result<_, _>>()?,
+            data: json_data.iter().map(parse).collect::<Result<_, _>>()?,
         })
     }
 }
@@ -112,26 +291,41 @@ impl From<&Timing> for JsonValue {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        // the mean and samples are written next to the stats, so the file stays readable for
+        // other tools. Stats of timings that only know their mean are not written.
+        for part in [1, 2] {
+            map.insert(
+                format!("part_{part}_nanos"),
+                value
+                    .part_nanos(part)
+                    .map_or(JsonValue::Null, JsonValue::Number),
+            );
 
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+            if let Some(samples) = value.part_samples(part) {
+                #[allow(clippy::cast_precision_loss)]
+                map.insert(
+                    format!("part_{part}_samples"),
+                    JsonValue::Number(samples as f64),
+                );
+                if let Some(stats) = value.part_stats(part) {
+                    map.insert(format!("part_{part}_stats"), JsonValue::from(stats));
+                }
+            }
+        }
 
-        map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        if value.timed_out {
+            map.insert("timed_out".into(), JsonValue::Boolean(true));
+        }
+
+        for (key, heap) in [
+            ("part_1_heap", &value.part_1_heap),
+            ("part_2_heap", &value.part_2_heap),
+        ] {
+            if let Some(heap) = heap {
+                map.insert(key.into(), JsonValue::from(heap));
+            }
+        }
 
         JsonValue::Object(map)
     }
@@ -141,6 +335,84 @@ impl TryFrom<&JsonValue> for Timing {
     type Error = String;
 
     fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let (json, mut timing) = Timing::try_from_common(value)?;
+
+        let nanos = |key: &str| {
+            json.get(key)
+                .map(|v| {
+                    if v.is_null() {
+                        None
+                    } else {
+                        v.get::<f64>().copied()
+                    }
+                })
+                .ok_or(format!("Expected timing.{key} to be null or a number."))
+        };
+
+        let samples = |key: &str| {
+            json.get(key)
+                .map(|v| {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    v.get::<f64>()
+                        .map(|x| *x as u128)
+                        .ok_or(format!("Expected timing.{key} to be a number."))
+                })
+                .transpose()
+        };
+
+        // the stats take precedence, files written by hand may only contain the mean.
+        let mean_only = |part: u8| -> Result<Option<BenchStats>, String> {
+            let samples = samples(&format!("part_{part}_samples"))?.unwrap_or(0);
+            Ok(
+                nanos(&format!("part_{part}_nanos"))?.map(|mean| BenchStats {
+                    samples,
+                    ..BenchStats::from_mean(mean)
+                }),
+            )
+        };
+
+        if timing.part_1_stats.is_none() {
+            timing.part_1_stats = mean_only(1)?;
+        }
+        if timing.part_2_stats.is_none() {
+            timing.part_2_stats = mean_only(2)?;
+        }
+
+        Ok(timing)
+    }
+}
+
+impl Timing {
+    /// Parse a timing of a version 1 file, whose durations are formatted strings like `"74.1ns"`.
+    fn try_from_v1(value: &JsonValue) -> Result<Self, String> {
+        let (json, mut timing) = Timing::try_from_common(value)?;
+
+        let formatted = |key: &str| {
+            json.get(key)
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or(format!("Expected timing.{key} to be null or string."))
+        };
+
+        let part_1 = formatted("part_1")?;
+        let part_2 = formatted("part_2")?;
+
+        // prefer the exact mean of the stats over the rounded, formatted duration.
+        if timing.part_1_stats.is_none() {
+            timing.part_1_stats = part_1
+                .and_then(|x| parse_nanos(x))
+                .map(BenchStats::from_mean);
+        }
+        if timing.part_2_stats.is_none() {
+            timing.part_2_stats = part_2
+                .and_then(|x| parse_nanos(x))
+                .map(BenchStats::from_mean);
+        }
+
+        Ok(timing)
+    }
+
+    /// Parse the fields that all versions share. Durations are left empty.
+    fn try_from_common(value: &JsonValue) -> Result<(&HashMap<String, JsonValue>, Self), String> {
         let json = value
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
@@ -151,27 +423,42 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
-
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
-
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
-
-        Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
-        })
+        // stats are optional, timings stored by older versions do not contain them.
+        let part_1_stats = json
+            .get("part_1_stats")
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let part_2_stats = json
+            .get("part_2_stats")
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let part_1_heap = json
+            .get("part_1_heap")
+            .map(HeapStats::try_from)
+            .transpose()?;
+
+        let part_2_heap = json
+            .get("part_2_heap")
+            .map(HeapStats::try_from)
+            .transpose()?;
+
+        let timed_out = json
+            .get("timed_out")
+            .is_some_and(|v| v.get::<bool>() == Some(&true));
+
+        Ok((
+            json,
+            Timing {
+                part_1_stats,
+                part_2_stats,
+                part_1_heap,
+                part_2_heap,
+                timed_out,
+                ..Timing::empty(day)
+            },
+        ))
     }
 }
 
@@ -179,7 +466,7 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{day, template::stats::BenchStats};
 
     use super::{Timing, Timings};
 
@@ -188,21 +475,27 @@ mod tests {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    part_1_stats: Some(BenchStats::from_mean(10_000_000.0)),
+                    part_2_stats: Some(BenchStats::from_mean(20_000_000.0)),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    timed_out: false,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    part_1_stats: Some(BenchStats::from_mean(30_000_000.0)),
+                    part_2_stats: Some(BenchStats::from_mean(40_000_000.0)),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    timed_out: false,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
-                    total_nanos: 4e+10,
+                    part_1_stats: Some(BenchStats::from_mean(40_000_000.0)),
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    timed_out: false,
                 },
             ],
         }
@@ -210,17 +503,95 @@ mod tests {
 
     mod deserialization {
         use crate::{day, template::timings::Timings};
+        use tinyjson::JsonValue;
 
         #[test]
         fn handles_json_timings() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1_nanos": 1000000, "part_1_samples": 10, "part_2_nanos": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.part_nanos(1), Some(1_000_000_f64));
+            assert_eq!(timing.part_samples(1), Some(10));
+            assert_eq!(timing.part_nanos(2), None);
+            assert_eq!(timing.part_samples(2), None);
+            assert_eq!(timing.total_nanos(), 1_000_000_f64);
+        }
+
+        #[test]
+        fn migrates_formatted_timings() {
             let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
-            assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.part_nanos(1), Some(1_000_000_f64));
+            assert_eq!(timing.part_samples(1), None);
+            assert_eq!(timing.part_nanos(2), None);
+        }
+
+        #[test]
+        fn roundtrips_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1.5µs", "part_2": "2ms", "total_nanos": 2001500 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            assert!(json.contains(r#""version":2"#));
+
+            let migrated = Timings::try_from(json).unwrap();
+            assert_eq!(migrated.data[0].part_nanos(1), Some(1_500_f64));
+            assert_eq!(migrated.data[0].part_nanos(2), Some(2_000_000_f64));
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unknown_versions() {
+            let json = r#"{ "version": 3, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "samples": 10, "mean": 1000000, "min": 900000, "median": 1000000, "p95": 1200000, "max": 1300000, "std_dev": 1000, "outliers": 1 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.samples, 10);
+            assert_eq!(timing.part_samples(1), Some(10));
+            assert_eq!(stats.p95, 1_200_000_f64);
+            assert_eq!(stats.outliers, 1);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn writes_means_next_to_stats() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1_nanos": 1000000, "part_1_samples": 10, "part_2_nanos": 2000000, "part_1_stats": { "samples": 10, "mean": 1000000, "min": 900000, "median": 1000000, "p95": 1200000, "max": 1300000, "std_dev": 1000, "outliers": 1 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            assert!(json.contains(r#""part_1_samples":10"#));
+            assert!(json.contains(r#""part_2_nanos":2000000"#));
+            // only the mean of part 2 is known, so it has no stats.
+            assert!(!json.contains("part_2_stats"));
+
+            let roundtripped = Timings::try_from(json).unwrap();
+            assert_eq!(
+                roundtripped.data[0].part_1_stats,
+                timings.data[0].part_1_stats
+            );
+            assert_eq!(roundtripped.data[0].part_nanos(2), Some(2_000_000_f64));
+            assert_eq!(roundtripped.data[0].part_samples(2), None);
+        }
+
+        #[test]
+        fn handles_json_timings_with_heap() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_heap": { "total_bytes": 276, "total_blocks": 3, "max_bytes": 232 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let heap = timing.part_1_heap.as_ref().unwrap();
+            assert_eq!(heap.total_bytes, 276);
+            assert_eq!(heap.total_blocks, 3);
+            assert_eq!(heap.max_bytes, 232);
+            assert_eq!(timing.part_2_heap, None);
         }
 
         #[test]
@@ -253,7 +624,7 @@ mod tests {
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
-            let value = JsonValue::try_from(timings).unwrap();
+            let value = JsonValue::from(timings);
             assert_eq!(
                 value
                     .get::<HashMap<String, JsonValue>>()
@@ -271,7 +642,10 @@ mod tests {
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                stats::BenchStats,
+                timings::{Timing, Timings},
+            },
         };
 
         #[test]
@@ -279,13 +653,15 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
+                    part_1_stats: Some(BenchStats::from_mean(1_000_000.0)),
+                    part_2_stats: Some(BenchStats::from_mean(2_000_000.0)),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    timed_out: false,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert!(timings.is_day_complete(day!(1)));
         }
 
         #[test]
@@ -293,13 +669,15 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
-                    total_nanos: 1_000_000_000_f64,
+                    part_1_stats: Some(BenchStats::from_mean(1_000_000.0)),
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    timed_out: false,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings.is_day_complete(day!(1)));
         }
 
         #[test]
@@ -307,20 +685,25 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    timed_out: false,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings.is_day_complete(day!(1)));
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                stats::{BenchStats, HeapStats},
+                timings::{Timing, Timings},
+            },
         };
 
         use super::get_mock_timings;
@@ -331,9 +714,11 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    timed_out: false,
                 }],
             };
             let merged = timings.merge(&other);
@@ -351,9 +736,11 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    timed_out: false,
                 }],
             };
             let merged = timings.merge(&other);
@@ -361,7 +748,7 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].total_nanos(), 0_f64);
             assert_eq!(merged.data[2].day, day!(4));
         }
 
@@ -373,6 +760,41 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
 
+        #[test]
+        fn merges_single_parts() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![
+                    Timing {
+                        day: day!(2),
+                        part_2_stats: Some(BenchStats::from_mean(1_000_000.0)),
+                        part_1_stats: None,
+                        part_1_heap: None,
+                        part_2_heap: None,
+                        timed_out: false,
+                    },
+                    Timing {
+                        day: day!(3),
+                        part_1_stats: Some(BenchStats::from_mean(5_000_000.0)),
+                        part_2_stats: Some(BenchStats::from_mean(6_000_000.0)),
+                        part_1_heap: None,
+                        part_2_heap: None,
+                        timed_out: false,
+                    },
+                ],
+            };
+            let merged = timings.merge_part(&other, 2);
+
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[1].part_nanos(1), Some(30_000_000.0));
+            assert_eq!(merged.data[1].part_nanos(2), Some(1_000_000.0));
+            assert_eq!(merged.data[1].total_nanos(), 31_000_000_f64);
+            assert_eq!(merged.data[2].day, day!(3));
+            assert_eq!(merged.data[2].part_nanos(1), None);
+            assert_eq!(merged.data[2].part_nanos(2), Some(6_000_000.0));
+        }
+
         #[test]
         fn handles_empty_other_timings() {
             let timings = get_mock_timings();
@@ -380,5 +802,31 @@ mod tests {
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
         }
+
+        #[test]
+        fn merges_heap_usage() {
+            let heap = HeapStats {
+                total_bytes: 276,
+                total_blocks: 3,
+                max_bytes: 232,
+            };
+            let mut profiled = Timing::empty(day!(2));
+            profiled.part_2_heap = Some(heap.clone());
+            let mut unprofiled = Timing::empty(day!(3));
+            unprofiled.part_1_heap = Some(heap.clone());
+
+            let merged = get_mock_timings().merge_heap(&Timings {
+                data: vec![profiled, unprofiled],
+            });
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[1].part_nanos(1), Some(30_000_000.0));
+            assert_eq!(merged.data[1].part_2_heap, Some(heap.clone()));
+            assert_eq!(merged.data[2].day, day!(3));
+            assert_eq!(merged.data[2].part_nanos(1), None);
+
+            // benchmark runs do not measure heap usage, merging them keeps the stored one.
+            let rerun = merged.merge(&get_mock_timings());
+            assert_eq!(rerun.data[1].part_2_heap, Some(heap));
+        }
     }
 }
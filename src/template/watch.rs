@@ -0,0 +1,219 @@
+/// Polls the files a day depends on, so `cargo solve <day> --watch` can rerun it on change.
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    params, protocol::PartRecord, runner::InputSource, stats::format_nanos, Day, ANSI_BOLD,
+    ANSI_ITALIC, ANSI_RESET,
+};
+
+/// How often watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Escape sequence that clears the terminal and moves the cursor to the top.
+pub const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Modification times of all files a day depends on.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Snapshot {
+    files: HashMap<PathBuf, SystemTime>,
+}
+
+impl Snapshot {
+    /// Record the modification times of every watched file of `day`.
+    /// A custom input file outside of `data` is watched together with its parameter file.
+    pub fn take(day: Day, input: &InputSource) -> Self {
+        let mut files = HashMap::new();
+
+        for path in [format!("src/bin/{day}.rs"), "src/lib.rs".into()] {
+            insert_file(&mut files, Path::new(&path));
+        }
+
+        if let InputSource::File(path) = input {
+            insert_file(&mut files, path);
+            insert_file(&mut files, &params::sidecar_path(path));
+        }
+
+        insert_dir(&mut files, Path::new("src/template"), &|_| true);
+
+        for folder in ["data/inputs", "data/examples"] {
+            insert_dir(&mut files, Path::new(folder), &|path| {
+                is_day_data(path, day)
+            });
+        }
+
+        Snapshot { files }
+    }
+
+    /// Paths that were added, removed or modified since `previous`.
+    pub fn changes(&self, previous: &Snapshot) -> Vec<PathBuf> {
+        let mut changes: Vec<PathBuf> = self
+            .files
+            .iter()
+            .filter(|(path, modified)| previous.files.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .chain(
+                previous
+                    .files
+                    .keys()
+                    .filter(|path| !self.files.contains_key(*path))
+                    .cloned(),
+            )
+            .collect();
+
+        changes.sort();
+        changes
+    }
+}
+
+/// Whether `path` is an input, example or parameter file of `day`, e.g. `01.txt`, `01-2.txt` or `01.params`.
+pub fn is_day_data(path: &Path, day: Day) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+
+    let day = day.to_string();
+
+    name.strip_suffix(".txt")
+        .or_else(|| name.strip_suffix(".params"))
+        .and_then(|stem| stem.strip_prefix(&day))
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+}
+
+/// Block until a watched file of `day` changes, and return the changed paths.
+pub fn wait_for_change(
+    day: Day,
+    input: &InputSource,
+    previous: &Snapshot,
+) -> (Snapshot, Vec<PathBuf>) {
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let snapshot = Snapshot::take(day, input);
+        let changes = snapshot.changes(previous);
+
+        if !changes.is_empty() {
+            // editors tend to write files in several steps, let them settle.
+            thread::sleep(POLL_INTERVAL);
+            return (Snapshot::take(day, input), changes);
+        }
+    }
+}
+
+/// Print the answer and timing of every part next to the ones of the previous run.
+pub fn print_changes(previous: &[PartRecord], current: &[PartRecord]) {
+    println!("{ANSI_BOLD}Compared to the previous run{ANSI_RESET}");
+
+    for record in current {
+        let before = previous.iter().find(|r| r.part == record.part);
+        let answer = |r: &PartRecord| r.answer.clone().unwrap_or_else(|| "✖".into());
+        let duration = |r: &PartRecord| format_nanos(r.stats.mean);
+
+        match before {
+            Some(before) if before.answer == record.answer => println!(
+                "Part {}: {} {ANSI_ITALIC}(unchanged, {} → {}){ANSI_RESET}",
+                record.part,
+                answer(record),
+                duration(before),
+                duration(record),
+            ),
+            Some(before) => println!(
+                "Part {}: {} → {ANSI_BOLD}{}{ANSI_RESET} {ANSI_ITALIC}({} → {}){ANSI_RESET}",
+                record.part,
+                answer(before),
+                answer(record),
+                duration(before),
+                duration(record),
+            ),
+            None => println!(
+                "Part {}: {ANSI_BOLD}{}{ANSI_RESET} {ANSI_ITALIC}(new, {}){ANSI_RESET}",
+                record.part,
+                answer(record),
+                duration(record),
+            ),
+        }
+    }
+}
+
+fn insert_file(files: &mut HashMap<PathBuf, SystemTime>, path: &Path) {
+    if let Ok(modified) = fs::metadata(path).and_then(|m| m.modified()) {
+        files.insert(path.to_path_buf(), modified);
+    }
+}
+
+fn insert_dir(
+    files: &mut HashMap<PathBuf, SystemTime>,
+    dir: &Path,
+    filter: &dyn Fn(&Path) -> bool,
+) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.filter_map(Result::ok).map(|e| e.path()) {
+        if path.is_dir() {
+            insert_dir(files, &path, filter);
+        } else if filter(&path) {
+            insert_file(files, &path);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{is_day_data, Snapshot};
+    use crate::{day, template::runner::InputSource};
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+        time::{Duration, SystemTime},
+    };
+
+    #[test]
+    fn matches_day_data() {
+        assert!(is_day_data(Path::new("data/inputs/12.txt"), day!(12)));
+        assert!(is_day_data(Path::new("data/examples/12-2.txt"), day!(12)));
+        assert!(is_day_data(Path::new("data/examples/12.params"), day!(12)));
+        assert!(!is_day_data(Path::new("data/examples/12.md"), day!(12)));
+        assert!(!is_day_data(Path::new("data/examples/13.txt"), day!(12)));
+        assert!(!is_day_data(Path::new("data/examples/123.txt"), day!(12)));
+    }
+
+    #[test]
+    fn detects_changes() {
+        let now = SystemTime::now();
+        let later = now + Duration::from_secs(1);
+
+        let previous = Snapshot {
+            files: [("a".into(), now), ("b".into(), now), ("c".into(), now)].into(),
+        };
+        let current = Snapshot {
+            files: [("a".into(), now), ("b".into(), later), ("d".into(), now)].into(),
+        };
+
+        let expected: Vec<PathBuf> = vec!["b".into(), "c".into(), "d".into()];
+        assert_eq!(current.changes(&previous), expected);
+        assert!(current.changes(&current).is_empty());
+    }
+
+    #[test]
+    fn watches_custom_inputs() {
+        let path = env::temp_dir().join(format!("aoc-watch-input-{}.txt", std::process::id()));
+        fs::write(&path, "1 2\n").unwrap();
+
+        let snapshot = Snapshot::take(day!(25), &InputSource::File(path.clone()));
+        let _ = fs::remove_file(&path);
+
+        assert!(snapshot.files.contains_key(&path));
+        assert!(!Snapshot::take(day!(25), &InputSource::Puzzle)
+            .files
+            .contains_key(&path));
+    }
+}